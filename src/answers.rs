@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+use std::fs;
+use crate::days;
+use crate::runner::{Part,Run,Status};
+
+pub const PATH: &str = "./inputs/answers.txt";
+
+// Answers file: `dayN<TAB>part<TAB>answer` per line, with `\n` and `\\` escaped in the answer
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Answers {
+    answers: HashMap<(String, String), String>
+}
+
+// Helper
+pub(crate) fn escape (answer: &str) -> String {
+    answer.replace('\\', "\\\\").replace('\n', "\\n")
+}
+pub(crate) fn unescape (answer: &str) -> String {
+    let mut out = String::new();
+    let mut chars = answer.chars();
+    while let Some(c) = chars.next() {
+        match (c, chars.clone().next()) {
+            ('\\', Some('n')) => { out.push('\n'); chars.next(); },
+            ('\\', Some('\\')) => { out.push('\\'); chars.next(); },
+            (c, _) => out.push(c)
+        }
+    }
+    out
+}
+
+impl Answers {
+    pub fn parse (content: &str) -> Result<Self, String> {
+        let mut answers = HashMap::new();
+        for (index, line) in content.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let fields: Vec<&str> = line.splitn(3, '\t').collect();
+            if fields.len() != 3 || (fields[1] != "1" && fields[1] != "2") {
+                return Err(format!("line {}: expected `dayN<TAB>part<TAB>answer`", index + 1));
+            }
+            answers.insert((String::from(fields[0]), String::from(fields[1])), unescape(fields[2]));
+        }
+        Ok(Self { answers })
+    }
+
+    pub fn load (path: &str) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content).map_err(|err| format!("{}: {}", path, err)),
+            Err(_) => Ok(Self::default())
+        }
+    }
+
+    pub fn get (&self, name: &str, part: Part) -> Option<&str> {
+        self.answers
+            .get(&(String::from(name), part.to_string()))
+            .map(|answer| answer.as_str())
+    }
+
+    pub fn set (&mut self, name: &str, part: Part, answer: &str) {
+        self.answers.insert((String::from(name), part.to_string()), String::from(answer));
+    }
+
+    pub fn save (&self, path: &str) -> Result<(), String> {
+        fs::write(path, self.to_text()).map_err(|err| format!("{}: {}", path, err))
+    }
+
+    pub fn to_text (&self) -> String {
+        let mut keys: Vec<&(String, String)> = self.answers.keys().collect();
+        // By year then day, `day10` after `day9`
+        keys.sort_by_key(|(name, part)| (days::parse_id(name), name.clone(), part.clone()));
+        let mut out = String::from("# day\tpart\tanswer (`\\n` for multi-line answers)\n");
+        for key in keys {
+            out += &format!("{}\t{}\t{}\n", key.0, key.1, escape(&self.answers[key]));
+        }
+        out
+    }
+}
+
+// Turn a successful run into `Wrong` or `Unverified` according to the stored answers
+pub fn verify (run: &mut Run, answers: &Answers) {
+    if run.status != Status::Ok {
+        return;
+    }
+    match answers.get(&run.name, run.part) {
+        None => run.status = Status::Unverified,
+        Some(expected) if expected != run.answer => run.status = Status::Wrong(String::from(expected)),
+        Some(_) => {}
+    }
+}
+
+// Used by the tests generated for every registered day
+#[cfg(test)]
+pub fn check (solution: &dyn crate::days::Solution) {
+    use crate::params::Params;
+    use crate::runner;
+    let answers = Answers::load(PATH).unwrap();
+    for part in &[Part::One, Part::Two] {
+        let expected = match answers.get(&solution.name(), *part) {
+            Some(expected) => expected,
+            None => continue
+        };
+        let input = runner::Source::Default.read(solution).unwrap();
+        let run = runner::run_part(solution, *part, &input, &Params::defaults(solution.params()));
+        assert_eq!(run.status, Status::Ok, "{} part {}", run.name, run.part);
+        assert_eq!(run.answer, expected, "{} part {}", run.name, run.part);
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::Answers;
+    use crate::runner::{Part,Run,Status};
+
+    #[test]
+    fn answers_file () {
+        let mut answers = Answers::parse("# comment\nday1\t1\t42\nday8\t2\t # \\n#\\\\n\n").unwrap();
+        assert_eq!(answers.get("day1", Part::One), Some("42"));
+        assert_eq!(answers.get("day1", Part::Two), None);
+        assert_eq!(answers.get("day8", Part::Two), Some(" # \n#\\n"));
+
+        answers.set("day10", Part::One, "7");
+        assert_eq!(Answers::parse(&answers.to_text()).unwrap(), answers);
+        assert!(answers.to_text().ends_with("day8\t2\t # \\n#\\\\n\nday10\t1\t7\n"));
+        answers.set("2020/day2", Part::One, "3");
+        answers.set("2018/day25", Part::One, "1");
+        assert!(answers.to_text().starts_with("# day\tpart\tanswer (`\\n` for multi-line answers)\n2018/day25\t1\t1\nday1\t1\t42\n"));
+        assert!(answers.to_text().ends_with("day10\t1\t7\n2020/day2\t1\t3\n"));
+        assert!(Answers::parse("day1 1 42").is_err());
+    }
+
+    #[test]
+    fn answers_verify () {
+        let answers = Answers::parse("day1\t1\t42\n").unwrap();
+        let run = |part, answer: &str| Run {
+            name: String::from("day1"),
+            part,
+            answer: String::from(answer),
+            duration: Default::default(),
+            status: Status::Ok,
+            memory: None,
+            cpu: None,
+            cached: false
+        };
+
+        let mut ok = run(Part::One, "42");
+        super::verify(&mut ok, &answers);
+        assert_eq!(ok.status, Status::Ok);
+
+        let mut wrong = run(Part::One, "43");
+        super::verify(&mut wrong, &answers);
+        assert_eq!(wrong.status, Status::Wrong(String::from("42")));
+
+        let mut unverified = run(Part::Two, "1");
+        super::verify(&mut unverified, &answers);
+        assert_eq!(unverified.status, Status::Unverified);
+    }
+}
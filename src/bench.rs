@@ -0,0 +1,247 @@
+use std::fs;
+use std::time::{Duration,Instant};
+use crate::days::Solution;
+use crate::params::Params;
+use crate::runner::{self,Part};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub name: String,
+    pub part: Part,
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub name: String,
+    pub part: Part,
+    pub baseline: Duration,
+    pub current: Duration,
+    pub change: f64,
+    pub regression: bool
+}
+
+// Helper
+pub(crate) fn statistics (name: String, part: Part, mut samples: Vec<Duration>) -> Stats {
+    samples.sort();
+    let n = samples.len();
+    let nanos: Vec<f64> = samples.iter().map(|sample| sample.as_nanos() as f64).collect();
+    let mean = nanos.iter().sum::<f64>() / n as f64;
+    let variance = nanos.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n as f64;
+    let median = if n % 2 == 1 {
+        nanos[n / 2]
+    } else {
+        (nanos[n / 2 - 1] + nanos[n / 2]) / 2.0
+    };
+
+    Stats {
+        name,
+        part,
+        iterations: n,
+        min: samples[0],
+        median: Duration::from_nanos(median as u64),
+        mean: Duration::from_nanos(mean as u64),
+        stddev: Duration::from_nanos(variance.sqrt() as u64)
+    }
+}
+
+// Bench
+pub fn bench (solution: &dyn Solution, part: Part, input: &str, params: &Params, warmup: usize, iterations: usize) -> Result<Stats, String> {
+    let run = || match part {
+        Part::One => solution.part1(input, params),
+        Part::Two => solution.part2(input, params)
+    };
+
+    if iterations == 0 {
+        return Err(String::from("at least one iteration is needed"));
+    }
+    let mut samples = Vec::with_capacity(iterations);
+    for i in 0..warmup + iterations {
+        let start = Instant::now();
+        runner::catch(run)
+            .map_err(|message| runner::panicked(solution, part, &message))?
+            .map_err(|err| err.at(solution, part).to_string())?;
+        if i >= warmup {
+            samples.push(start.elapsed());
+        }
+    }
+
+    Ok(statistics(solution.name(), part, samples))
+}
+
+// Results file: one tab-separated line per part, durations in nanoseconds
+pub fn save (path: &str, stats: &[Stats]) -> Result<(), String> {
+    let mut content = String::from("# day\tpart\titerations\tmin\tmedian\tmean\tstddev\n");
+    for s in stats {
+        content += &format!(
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}\n",
+            s.name, s.part, s.iterations, s.min.as_nanos(), s.median.as_nanos(), s.mean.as_nanos(), s.stddev.as_nanos()
+        );
+    }
+    fs::write(path, content).map_err(|err| format!("{}: {}", path, err))
+}
+
+pub fn load (path: &str) -> Result<Vec<Stats>, String> {
+    let content = fs::read_to_string(path).map_err(|err| format!("{}: {}", path, err))?;
+    content
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|(index, line)| {
+            let invalid = || format!("{}:{}: invalid benchmark line", path, index + 1);
+            let fields: Vec<&str> = line.split('\t').collect();
+            if fields.len() != 7 {
+                return Err(invalid());
+            }
+            let part = match fields[1] {
+                "1" => Part::One,
+                "2" => Part::Two,
+                _ => return Err(invalid())
+            };
+            let nanos = |i: usize| fields[i].parse().map(Duration::from_nanos).map_err(|_| invalid());
+            Ok(Stats {
+                name: String::from(fields[0]),
+                part,
+                iterations: fields[2].parse().map_err(|_| invalid())?,
+                min: nanos(3)?,
+                median: nanos(4)?,
+                mean: nanos(5)?,
+                stddev: nanos(6)?
+            })
+        })
+        .collect()
+}
+
+// Compare medians, flagging parts slower than the baseline by more than `threshold` percent
+pub fn compare (current: &[Stats], baseline: &[Stats], threshold: f64) -> Vec<Comparison> {
+    current
+        .iter()
+        .filter_map(|s| {
+            let base = baseline.iter().find(|b| b.name == s.name && b.part == s.part)?;
+            let change = if base.median.as_nanos() == 0 {
+                0.0
+            } else {
+                (s.median.as_nanos() as f64 / base.median.as_nanos() as f64 - 1.0) * 100.0
+            };
+            Some(Comparison {
+                name: s.name.clone(),
+                part: s.part,
+                baseline: base.median,
+                current: s.median,
+                change,
+                regression: change > threshold
+            })
+        })
+        .collect()
+}
+
+// Table
+pub fn header () -> String {
+    format!("{:<6} {:<4} {:>6} {:>12} {:>12} {:>12} {:>12}", "Day", "Part", "Iter", "Min", "Median", "Mean", "Stddev")
+}
+
+pub fn row (s: &Stats) -> String {
+    format!(
+        "{:<6} {:<4} {:>6} {:>12} {:>12} {:>12} {:>12}",
+        s.name, s.part, s.iterations,
+        format!("{:.2?}", s.min), format!("{:.2?}", s.median), format!("{:.2?}", s.mean), format!("{:.2?}", s.stddev)
+    )
+}
+
+pub fn comparison_row (c: &Comparison) -> String {
+    format!(
+        "{:<6} {:<4} {:>12} -> {:>12} {:>+8.1}%{}",
+        c.name, c.part, format!("{:.2?}", c.baseline), format!("{:.2?}", c.current), c.change,
+        if c.regression { "  REGRESSION" } else { "" }
+    )
+}
+
+// Saved benchmarks kept as history, e.g. with `--save bench/2019-12-24.tsv`
+pub const HISTORY: &str = "./bench";
+
+// Every `.tsv` of `dir` by file name (oldest first when named by date), none when there's no such directory
+pub fn history (dir: &str) -> Result<Vec<(String, Vec<Stats>)>, String> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return Ok(Vec::new())
+    };
+    let mut paths: Vec<std::path::PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|extension| extension == "tsv"))
+        .collect();
+    paths.sort();
+    paths
+        .iter()
+        .map(|path| {
+            let name = path.file_stem().map_or(String::new(), |name| name.to_string_lossy().to_string());
+            Ok((name, load(&path.to_string_lossy())?))
+        })
+        .collect()
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use crate::days;
+    use crate::runner::Part;
+
+    fn ms (values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|v| Duration::from_millis(*v)).collect()
+    }
+
+    #[test]
+    fn bench_statistics () {
+        let stats = super::statistics(String::from("day1"), Part::One, ms(&[4, 1, 3, 2]));
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        assert_eq!(stats.stddev.as_micros(), 1118);
+    }
+
+    #[test]
+    fn bench_run_and_compare () {
+        let day1 = days::find("day1").unwrap();
+        let stats = super::bench(day1, Part::One, "12\n14", &Default::default(), 1, 5).unwrap();
+        assert_eq!(stats.iterations, 5);
+        assert!(super::bench(day1, Part::One, "x", &Default::default(), 0, 1).is_err());
+
+        let baseline = super::statistics(String::from("day1"), Part::One, ms(&[10]));
+        let faster = super::statistics(String::from("day1"), Part::One, ms(&[9]));
+        let slower = super::statistics(String::from("day1"), Part::One, ms(&[12]));
+        let baseline = vec![baseline];
+        assert!(!super::compare(&[faster], &baseline, 10.0)[0].regression);
+        let comparison = super::compare(&[slower], &baseline, 10.0);
+        assert!(comparison[0].regression);
+        assert!((comparison[0].change - 20.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn bench_file () {
+        let path = std::env::temp_dir().join(format!("aoc-bench-{}.tsv", std::process::id()));
+        let path = path.to_str().unwrap();
+        let stats = vec![super::statistics(String::from("day3"), Part::Two, ms(&[5, 7]))];
+        super::save(path, &stats).unwrap();
+        assert_eq!(super::load(path).unwrap(), stats);
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[test]
+    fn bench_history () {
+        let dir = std::env::temp_dir().join(format!("aoc-history-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let stats = vec![super::statistics(String::from("day1"), Part::One, ms(&[5]))];
+        super::save(dir.join("2019-12-02.tsv").to_str().unwrap(), &stats).unwrap();
+        super::save(dir.join("2019-12-01.tsv").to_str().unwrap(), &[]).unwrap();
+        std::fs::write(dir.join("notes.txt"), "not a benchmark").unwrap();
+
+        let history = super::history(dir.to_str().unwrap()).unwrap();
+        assert_eq!(history, vec![(String::from("2019-12-01"), vec![]), (String::from("2019-12-02"), stats)]);
+        assert_eq!(super::history("./missing-directory"), Ok(vec![]));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,127 @@
+use std::collections::HashMap;
+use std::fs;
+use std::time::Duration;
+use crate::answers::{escape,unescape};
+use crate::days::{self,Solution};
+use crate::params::Params;
+use crate::runner::{Part,Run,Status};
+
+// Under target/ so that `cargo clean` clears it too
+pub const PATH: &str = "./target/cache.tsv";
+
+// Answers of previous runs, one `day<TAB>part<TAB>input hash<TAB>version<TAB>duration (ns)<TAB>answer` per line
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Cache {
+    entries: HashMap<String, (Duration, String)>
+}
+
+// Helper
+// 64 bits FNV-1a, collisions between inputs of a day are unlikely enough
+fn fnv1a (bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| (hash ^ *byte as u64).wrapping_mul(0x100000001b3))
+}
+
+// Key of a part run on `input`: the version changes with the sources of the day, its version string and parameters
+pub fn key (solution: &dyn Solution, part: Part, input: &str, params: &Params) -> String {
+    let version = format!("{}\0{}\0{}", days::sources(solution).concat(), solution.version(), params.describe());
+    format!("{}\t{}\t{:016x}\t{:016x}", solution.name(), part, fnv1a(input.as_bytes()), fnv1a(version.as_bytes()))
+}
+
+impl Cache {
+    // Lines that don't parse are dropped, it's only a cache
+    pub fn parse (text: &str) -> Self {
+        let entries = text
+            .lines()
+            .filter_map(|line| {
+                let fields: Vec<&str> = line.splitn(6, '\t').collect();
+                match fields.as_slice() {
+                    [day, part, input, version, duration, answer] => {
+                        let duration = Duration::from_nanos(duration.parse().ok()?);
+                        Some((format!("{}\t{}\t{}\t{}", day, part, input, version), (duration, unescape(answer))))
+                    },
+                    _ => None
+                }
+            })
+            .collect();
+        Self { entries }
+    }
+
+    // A missing cache is an empty one
+    pub fn load (path: &str) -> Self {
+        fs::read_to_string(path).map_or_else(|_| Self::default(), |text| Self::parse(&text))
+    }
+
+    // Run of `solution` from the cache, its duration being the one of the run that was cached
+    pub fn get (&self, key: &str, solution: &dyn Solution, part: Part) -> Option<Run> {
+        let (duration, answer) = self.entries.get(key)?;
+        Some(Run {
+            name: solution.name(),
+            part,
+            answer: answer.clone(),
+            duration: *duration,
+            status: Status::Ok,
+            memory: None,
+            cpu: None,
+            cached: true
+        })
+    }
+
+    // Only solved parts are cached
+    pub fn insert (&mut self, key: String, run: &Run) {
+        if run.status == Status::Ok && !run.cached {
+            self.entries.insert(key, (run.duration, run.answer.clone()));
+        }
+    }
+
+    pub fn to_text (&self) -> String {
+        let mut lines: Vec<String> = self.entries
+            .iter()
+            .map(|(key, (duration, answer))| format!("{}\t{}\t{}\n", key, duration.as_nanos(), escape(answer)))
+            .collect();
+        lines.sort();
+        lines.concat()
+    }
+
+    pub fn save (&self, path: &str) -> Result<(), String> {
+        fs::write(path, self.to_text()).map_err(|err| format!("{}: {}", path, err))
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use crate::days;
+    use crate::params::Params;
+    use crate::runner::{self,Part};
+
+    #[test]
+    fn cache_key () {
+        let day1 = days::find("day1").unwrap();
+        let day8 = days::find("day8").unwrap();
+        let key = super::key(day1, Part::One, "12", &Params::default());
+        assert!(key.starts_with("day1\t1\t"));
+        assert_eq!(key, super::key(day1, Part::One, "12", &Params::default()));
+        assert_ne!(key, super::key(day1, Part::One, "14", &Params::default()));
+        assert_ne!(key, super::key(day1, Part::Two, "12", &Params::default()));
+
+        let defaults = Params::defaults(day8.params());
+        let params = Params::resolve(day8.params(), &[(String::from("width"), 3)]);
+        assert_ne!(super::key(day8, Part::One, "0", &defaults), super::key(day8, Part::One, "0", &params));
+    }
+
+    #[test]
+    fn cache_roundtrip () {
+        let day1 = days::find("day1").unwrap();
+        let key = super::key(day1, Part::Two, "1969", &Params::default());
+        let run = runner::run_part(day1, Part::Two, "1969", &Params::default());
+        let mut cache = super::Cache::default();
+        cache.insert(key.clone(), &run);
+        cache.insert(String::from("day1\t1\tfailed"), &runner::run_part(day1, Part::One, "x", &Params::default()));
+
+        let cache = super::Cache::parse(&(cache.to_text() + "garbage\n"));
+        let cached = cache.get(&key, day1, Part::Two).unwrap();
+        assert_eq!((cached.answer.as_str(), cached.duration, cached.cached), ("966", run.duration, true));
+        assert!(cache.get("day1\t1\tfailed", day1, Part::One).is_none());
+        assert_eq!(cache.to_text().lines().count(), 1);
+    }
+}
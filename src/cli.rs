@@ -0,0 +1,265 @@
+use std::time::Duration;
+use crate::ocr;
+use crate::params::{self,Override};
+use crate::runner::{Limits,Source};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Text,
+    Json
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Options {
+    pub command: String,
+    pub arguments: Vec<String>,
+    pub bench: Option<usize>,
+    pub warmup: usize,
+    pub save: Option<String>,
+    pub baseline: Option<String>,
+    pub threshold: f64,
+    pub verify: bool,
+    pub record: bool,
+    pub format: Format,
+    pub input: Option<String>,
+    pub stdin: bool,
+    pub example: Option<String>,
+    pub quiet: bool,
+    pub jobs: usize,
+    pub timeout: Option<Duration>,
+    pub memory: bool,
+    pub raw: bool,
+    pub params: Vec<Override>,
+    pub no_cache: bool,
+    pub report: Option<String>,
+    pub implementation: Option<String>,
+    pub cross_check: bool
+}
+impl Default for Options {
+    fn default () -> Self {
+        Self {
+            command: String::new(),
+            arguments: Vec::new(),
+            bench: None,
+            warmup: 3,
+            save: None,
+            baseline: None,
+            threshold: 10.0,
+            verify: false,
+            record: false,
+            format: Format::Text,
+            input: None,
+            stdin: false,
+            example: None,
+            quiet: false,
+            jobs: 1,
+            timeout: None,
+            memory: false,
+            raw: false,
+            params: Vec::new(),
+            no_cache: false,
+            report: None,
+            implementation: None,
+            cross_check: false
+        }
+    }
+}
+impl Options {
+    // The inline argument, `--input`, `--stdin` and `--example` are mutually exclusive
+    pub fn source (&self) -> Result<Source, String> {
+        let mut sources = Vec::new();
+        if let Some(input) = self.arguments.first() {
+            sources.push(Source::Inline(input.clone()));
+        }
+        if let Some(path) = &self.input {
+            sources.push(Source::File(path.clone()));
+        }
+        if self.stdin {
+            sources.push(Source::Stdin);
+        }
+        if let Some(name) = &self.example {
+            sources.push(Source::Example(name.clone()));
+        }
+        match sources.len() {
+            0 => Ok(Source::Default),
+            1 => Ok(sources.remove(0)),
+            _ => Err(String::from("Please provide a single input (inline, --input, --stdin or --example)"))
+        }
+    }
+
+    pub fn limits (&self) -> Limits {
+        Limits { timeout: self.timeout, memory: self.memory }
+    }
+
+    // `--param` overrides, with `--raw` setting the parameter of the days reading rendered text
+    pub fn overrides (&self) -> Vec<Override> {
+        let mut overrides = self.params.clone();
+        if self.raw {
+            overrides.push((String::from(ocr::RAW.name), 1));
+        }
+        overrides
+    }
+}
+
+// Helper
+fn number<T: std::str::FromStr> (flag: &str, value: String) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("Invalid value {:?} for {}", value, flag))
+}
+
+// Seconds, or a number followed by `ms`, `s` or `m`
+fn duration (flag: &str, value: String) -> Result<Duration, String> {
+    let (number, scale) = if let Some(number) = value.strip_suffix("ms") {
+        (number, 0.001)
+    } else if let Some(number) = value.strip_suffix('s') {
+        (number, 1.0)
+    } else if let Some(number) = value.strip_suffix('m') {
+        (number, 60.0)
+    } else {
+        (value.as_str(), 1.0)
+    };
+    match number.parse::<f64>() {
+        Ok(number) if number > 0.0 && number.is_finite() => Ok(Duration::from_secs_f64(number * scale)),
+        _ => Err(format!("Invalid value {:?} for {}", value, flag))
+    }
+}
+
+// Parse `<command> [arguments...] [--flag value | --flag=value...]`
+pub fn parse (args: &[String]) -> Result<Options, String> {
+    let mut options = Options::default();
+    let mut args = args.iter().cloned();
+
+    while let Some(arg) = args.next() {
+        if !arg.starts_with("--") && arg != "-q" && arg != "-j" {
+            if options.command.is_empty() {
+                options.command = arg;
+            } else {
+                options.arguments.push(arg);
+            }
+            continue;
+        }
+
+        // Switches without a value
+        match arg.as_str() {
+            "--verify" => { options.verify = true; continue; },
+            "--record" => { options.record = true; continue; },
+            "--stdin" => { options.stdin = true; continue; },
+            "--quiet" | "-q" => { options.quiet = true; continue; },
+            "--memory" => { options.memory = true; continue; },
+            "--raw" => { options.raw = true; continue; },
+            "--no-cache" => { options.no_cache = true; continue; },
+            "--cross-check" => { options.cross_check = true; continue; },
+            _ => {}
+        }
+
+        let (flag, inline_value) = match arg.split_once('=') {
+            Some((flag, value)) => (String::from(flag), Some(String::from(value))),
+            None => (arg.clone(), None)
+        };
+        let mut value = || inline_value
+            .clone()
+            .or_else(|| args.next())
+            .ok_or(format!("Missing value for {}", flag));
+
+        match flag.as_str() {
+            "--bench" => options.bench = match number(&flag, value()?)? {
+                0 => return Err(String::from("--bench needs at least one iteration")),
+                iterations => Some(iterations)
+            },
+            "--warmup" => options.warmup = number(&flag, value()?)?,
+            "--save" => options.save = Some(value()?),
+            "--baseline" => options.baseline = Some(value()?),
+            "--threshold" => options.threshold = number(&flag, value()?)?,
+            // 0 means one job per available core
+            "--jobs" | "-j" => options.jobs = match number(&flag, value()?)? {
+                0 => std::thread::available_parallelism().map_or(1, |n| n.get()),
+                jobs => jobs
+            },
+            // Repeated for each parameter
+            "--param" => options.params.push(params::parse(&value()?)?),
+            "--timeout" => options.timeout = Some(duration(&flag, value()?)?),
+            "--input" => options.input = Some(value()?),
+            "--example" => options.example = Some(value()?),
+            "--report" => options.report = Some(value()?),
+            "--impl" => options.implementation = Some(value()?),
+            "--format" => options.format = match value()?.as_str() {
+                "text" => Format::Text,
+                "json" => Format::Json,
+                other => return Err(format!("Unknown format {:?} (text or json)", other))
+            },
+            _ => return Err(format!("Unknown option {}", flag))
+        }
+    }
+
+    if options.command.is_empty() {
+        return Err(String::from("Please provide a day"));
+    }
+    Ok(options)
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    fn args (line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn cli_parse () {
+        let options = super::parse(&args("day5 --bench 20 --save=bench.tsv 1,0,99")).unwrap();
+        assert_eq!(options.command, "day5");
+        assert_eq!(options.arguments, vec!["1,0,99"]);
+        assert_eq!(options.bench, Some(20));
+        assert_eq!(options.save.as_deref(), Some("bench.tsv"));
+        assert_eq!(options.warmup, 3);
+        assert!(!options.verify);
+        assert!(!options.no_cache);
+        assert!(super::parse(&args("all --no-cache")).unwrap().no_cache);
+        assert_eq!(super::parse(&args("all --report out.html")).unwrap().report.as_deref(), Some("out.html"));
+        let options = super::parse(&args("day16 --impl prefix-sum --cross-check")).unwrap();
+        assert_eq!((options.implementation.as_deref(), options.cross_check), (Some("prefix-sum"), true));
+        assert!(super::parse(&args("day1..day5 --verify")).unwrap().verify);
+        assert_eq!(super::parse(&args("all -j 4")).unwrap().jobs, 4);
+        assert!(super::parse(&args("all --jobs=0")).unwrap().jobs >= 1);
+        let limits = |line| super::parse(&args(line)).unwrap().limits();
+        assert_eq!(limits("all"), crate::runner::Limits::default());
+        assert_eq!(limits("all --timeout 2.5").timeout, Some(Duration::from_millis(2500)));
+        assert_eq!(limits("all --timeout=300ms").timeout, Some(Duration::from_millis(300)));
+        assert_eq!(limits("all --timeout 1m --memory").timeout, Some(Duration::from_secs(60)));
+        assert!(limits("all --memory").memory);
+        assert!(super::parse(&args("day8 --raw")).unwrap().raw);
+        assert_eq!(super::parse(&args("day8 --raw --param width=3")).unwrap().overrides(), vec![(String::from("width"), 3), (String::from("raw"), 1)]);
+        let options = super::parse(&args("day8 --param width=3 --param=height=2")).unwrap();
+        assert_eq!(options.params, vec![(String::from("width"), 3), (String::from("height"), 2)]);
+        assert_eq!(super::parse(&args("all --format=json")).unwrap().format, super::Format::Json);
+    }
+
+    #[test]
+    fn cli_errors () {
+        assert!(super::parse(&args("")).is_err());
+        assert!(super::parse(&args("all --bench")).is_err());
+        assert!(super::parse(&args("all --bench ten")).is_err());
+        assert!(super::parse(&args("all --bench 0")).is_err());
+        assert!(super::parse(&args("all --frobnicate")).is_err());
+        assert!(super::parse(&args("all --format xml")).is_err());
+        assert!(super::parse(&args("all --timeout 0")).is_err());
+        assert!(super::parse(&args("all --timeout=5h")).is_err());
+        assert!(super::parse(&args("day8 --param width")).is_err());
+        assert!(super::parse(&args("day3 R8,U5 --stdin")).unwrap().source().is_err());
+    }
+
+    #[test]
+    fn cli_source () {
+        use crate::runner::Source;
+        let source = |line| super::parse(&args(line)).unwrap().source().unwrap();
+        assert_eq!(source("day3"), Source::Default);
+        assert_eq!(source("day3 R8,U5"), Source::Inline(String::from("R8,U5")));
+        assert_eq!(source("day3 --input /tmp/day3.txt -q"), Source::File(String::from("/tmp/day3.txt")));
+        assert_eq!(source("day3 --stdin"), Source::Stdin);
+        assert_eq!(source("day3 --example=example1"), Source::Example(String::from("example1")));
+        assert!(super::parse(&args("day3 -q")).unwrap().quiet);
+    }
+}
@@ -0,0 +1,516 @@
+use std::fs;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration,Instant};
+use crate::answers::{self,Answers};
+use crate::bench;
+use crate::cache;
+use crate::cli::{self,Format};
+use crate::crosscheck;
+use crate::days;
+use crate::error;
+use crate::intcode;
+use crate::intcode::diff::{self,Patch};
+use crate::intcode::image::Image;
+use crate::memory;
+use crate::params::{self,Params};
+use crate::progress;
+use crate::runner::{self,Limits,Part,Source,Status};
+use crate::scaffold;
+use crate::watch;
+
+// Commands of the binary, each printing its output and returning whether everything went fine
+// (or an error to print): src/main.rs only parses the arguments and turns that into an exit code
+
+// Only runs of days have a JSON output, other commands refuse `--format json` rather than printing text
+fn text_only (options: &cli::Options, command: &str) -> Result<(), String> {
+    match options.format {
+        Format::Json => Err(format!("--format json is not supported by {}", command)),
+        Format::Text => Ok(())
+    }
+}
+
+pub fn image_command (args: &[String]) -> Result<(), String> {
+    let load = |path: &str| if path == "-" {
+        intcode::load_stdin()
+    } else {
+        intcode::load_file(path)
+    };
+
+    match args {
+        [command, source, destination, patches @ ..] if command == "pack" => {
+            let opcodes = load(source).map_err(|err| format!("{}: {}", source, err))?;
+            let mut image = Image::new(&opcodes);
+            for patch in patches {
+                // Either an inline `address=value` or a patch file
+                let patch = if patch.contains('=') {
+                    Patch::parse(patch).map_err(|err| format!("Invalid patch {:?}: {}", patch, err))?
+                } else {
+                    let text = fs::read_to_string(patch).map_err(|err| format!("{}: {}", patch, err))?;
+                    Patch::parse(&text).map_err(|err| format!("{}: {}", patch, err))?
+                };
+                for (address, value) in patch.cells {
+                    image = image.with_patch(address, value);
+                }
+            }
+            image.patch.check(image.opcodes.len()).map_err(|err| format!("Invalid patch: {}", err))?;
+            fs::write(destination, image.encode()).map_err(|err| format!("{}: {}", destination, err))
+        },
+        [command, before, after, rest @ ..] if command == "diff" => {
+            let before_opcodes = load(before).map_err(|err| format!("{}: {}", before, err))?;
+            let after_opcodes = load(after).map_err(|err| format!("{}: {}", after, err))?;
+            let changes = diff::diff(&before_opcodes, &after_opcodes);
+            print!("{}", diff::summarize(&changes));
+            if let Some(destination) = rest.first() {
+                let patch = Patch::from_changes(&changes);
+                fs::write(destination, patch.to_string()).map_err(|err| format!("{}: {}", destination, err))?;
+            }
+            Ok(())
+        },
+        [command, source, rest @ ..] if command == "unpack" => {
+            let bytes = fs::read(source).map_err(|err| format!("{}: {}", source, err))?;
+            let image = Image::decode(&bytes).map_err(|err| format!("{}: {}", source, err))?;
+            let apply = rest.iter().any(|arg| arg == "--apply");
+            let mut text = String::new();
+            let opcodes = if apply {
+                image.patched().map_err(|err| format!("{}: {}", source, err))?
+            } else {
+                for line in image.patch.to_string().lines() {
+                    text += &format!("# patch {}\n", line);
+                }
+                image.opcodes
+            };
+            text += &opcodes.iter().map(|o| o.to_string()).collect::<Vec<String>>().join(",");
+            text += "\n";
+            match rest.iter().find(|arg| *arg != "--apply") {
+                Some(destination) => fs::write(destination, text).map_err(|err| format!("{}: {}", destination, err)),
+                None => { print!("{}", text); Ok(()) }
+            }
+        },
+        _ => Err(String::from("Usage: image pack <program|-> <image> [address=value|patch-file...]\n       image unpack <image> [program] [--apply]\n       image diff <before> <after> [patch-file]"))
+    }
+}
+
+// `new dayN [title]` (or `new YYYY/dayN`), the title defaults to the registered one
+pub fn new_command (args: &[String]) -> Result<(), String> {
+    let (name, title) = match args {
+        [name] => (name, None),
+        [name, title] => (name, Some(title.clone())),
+        _ => return Err(String::from("Usage: new <dayN|YYYY/dayN> [title]"))
+    };
+    let (year, day) = scaffold::parse_id(name)?;
+    let title = title
+        .or_else(|| days::find(&format!("{}/day{}", year, day)).map(|solution| solution.title().to_string()))
+        .unwrap_or(format!("Day {}", day));
+    let written = scaffold::create(std::path::Path::new("."), year, day, &title)?;
+    if written.is_empty() {
+        println!("{} is already scaffolded", name);
+    }
+    for path in written {
+        println!("Wrote {}", path);
+    }
+    Ok(())
+}
+
+fn bench_command (options: &cli::Options, iterations: usize) -> Result<bool, String> {
+    let solutions = implementation(options, runner::select(&options.command)?)?;
+    params::check(&solutions, &options.params)?;
+    let source = options.source()?;
+    let baseline = match &options.baseline {
+        Some(path) => Some(bench::load(path)?),
+        None => None
+    };
+
+    println!("{}", bench::header());
+    let mut results = Vec::new();
+    let mut failed = false;
+    for solution in solutions {
+        let input = source.read(solution)?;
+        let params = Params::resolve(solution.params(), &options.overrides());
+        let (implemented1, implemented2) = solution.implemented();
+        for (part, implemented) in &[(Part::One, implemented1), (Part::Two, implemented2)] {
+            if !implemented {
+                continue;
+            }
+            match bench::bench(solution, *part, &input, &params, options.warmup, iterations) {
+                Ok(stats) => {
+                    println!("{}", bench::row(&stats));
+                    results.push(stats);
+                },
+                Err(err) => {
+                    println!("{:<6} {:<4} failed: {}", solution.name(), part, err);
+                    failed = true;
+                }
+            }
+        }
+    }
+
+    if let Some(baseline) = baseline {
+        let comparisons = bench::compare(&results, &baseline, options.threshold);
+        println!("\nCompared to {} (threshold {}%):", options.baseline.as_deref().unwrap_or(""), options.threshold);
+        for comparison in &comparisons {
+            println!("{}", bench::comparison_row(comparison));
+        }
+        failed |= comparisons.iter().any(|comparison| comparison.regression);
+    }
+    if let Some(path) = &options.save {
+        bench::save(path, &results)?;
+        println!("\nSaved to {}", path);
+    }
+    Ok(!failed)
+}
+
+// Days with their parts solved by the `--impl` implementation, where they have one by that name
+fn implementation (options: &cli::Options, solutions: Vec<&'static dyn days::Solution>) -> Result<Vec<&'static dyn days::Solution>, String> {
+    let implementation = match &options.implementation {
+        Some(implementation) => implementation,
+        None => return Ok(solutions)
+    };
+    days::check(&solutions, implementation)?;
+    // Leaked once per day, the selection is used until the process exits
+    Ok(solutions
+        .into_iter()
+        .map(|solution| -> &'static dyn days::Solution {
+            Box::leak(Box::new(days::Selected { solution, implementation: implementation.clone() }))
+        })
+        .collect())
+}
+
+// Run every implementation of the parts that have several on the same input, returns whether they all agree
+fn cross_check_command (options: &cli::Options) -> Result<bool, String> {
+    let solutions = runner::select(&options.command)?;
+    params::check(&solutions, &options.params)?;
+    let source = options.source()?;
+    if solutions.len() > 1 && !source.per_day() {
+        return Err(format!("{} can only be used with a single day", source.describe(solutions[0])));
+    }
+
+    let mut agreed = true;
+    let mut checked = 0;
+    for solution in solutions {
+        for part in &[Part::One, Part::Two] {
+            if days::implementations(solution, *part).len() < 2 {
+                continue;
+            }
+            let input = source.read(solution)?;
+            let params = Params::resolve(solution.params(), &options.overrides());
+            let checks = crosscheck::cross_check(solution, *part, &input, &params);
+            if checked == 0 {
+                println!("{}", crosscheck::header());
+            }
+            for check in &checks {
+                println!("{}", crosscheck::row(check, &checks[0]));
+            }
+            if !crosscheck::agree(&checks) {
+                println!("{} part {}: implementations disagree", solution.name(), part);
+                agreed = false;
+            }
+            checked += 1;
+        }
+    }
+    if checked == 0 {
+        return Err(format!("No part of {} has alternative implementations (see `list`)", options.command));
+    }
+    Ok(agreed)
+}
+
+// Re-run a day each time its input or examples change, until interrupted
+pub fn watch_command (options: &cli::Options) -> Result<(), String> {
+    text_only(options, "watch")?;
+    let solution = days::find(&options.command).ok_or("Usage: watch <dayN> [--example name | --input path] [--param name=value...]")?;
+    let solution = implementation(options, vec![solution])?[0];
+    let source = options.source()?;
+    params::check(&[solution], &options.params)?;
+    let params = Params::resolve(solution.params(), &options.overrides());
+    let mut previous: Option<Vec<runner::Run>> = None;
+    loop {
+        let files = watch::files(solution, &source);
+        let snapshot = watch::snapshot(&files);
+
+        // Clear the screen, then show the runs and what changed since the last ones
+        print!("\x1b[2J\x1b[H");
+        println!("Watching {} ({} files), Ctrl-C to stop\n", source.describe(solution), files.len());
+        match source.read(solution) {
+            Ok(input) => {
+                let input = Arc::new(input);
+                let runs: Vec<runner::Run> = [Part::One, Part::Two]
+                    .iter()
+                    .map(|part| runner::run_limited(solution, *part, &input, &params, options.limits()))
+                    .collect();
+                println!("{}", runner::header(options.memory));
+                for run in &runs {
+                    println!("{}", runner::row(run, options.memory));
+                }
+                if let Some(previous) = &previous {
+                    println!("\n{}", watch::changes(previous, &runs).join("\n"));
+                }
+                previous = Some(runs);
+            },
+            Err(err) => println!("{}", err)
+        }
+
+        while watch::snapshot(&watch::files(solution, &source)) == snapshot {
+            thread::sleep(Duration::from_millis(300));
+        }
+    }
+}
+
+const README: &str = "./README.md";
+
+// Run every day of the default year on its default input, verified against the stored answers, and regenerate the README table
+fn readme_command (options: &cli::Options, answers: &Answers) -> Result<(), String> {
+    let solutions = runner::select(&days::DEFAULT_YEAR.to_string())?;
+    // Parts that never finish are reported as such instead of blocking the table
+    let limits = Limits { timeout: Some(options.timeout.unwrap_or(Duration::from_secs(10))), memory: false };
+    let runs = runner::run_all(&solutions, &Source::Default, options.jobs, limits, &[], None, |run| {
+        answers::verify(run, answers);
+        println!("{}", runner::row(run, false));
+    });
+
+    let days: Vec<String> = solutions.iter().map(|solution| solution.name()).collect();
+    let readme = fs::read_to_string(README).map_err(|err| format!("{}: {}", README, err))?;
+    let readme = progress::update(&readme, &progress::table(&days, &runs))?;
+    fs::write(README, readme).map_err(|err| format!("{}: {}", README, err))?;
+    let stars = runs.iter().filter(|run| run.status == Status::Ok).count();
+    println!("\nUpdated {} ({} stars)", README, stars);
+    Ok(())
+}
+
+// Self-contained HTML page of a run, with the visuals of the days drawing one
+fn report_file (path: &str, options: &cli::Options, solutions: &[&'static dyn days::Solution], source: &Source, runs: &[runner::Run]) -> Result<(), String> {
+    let visuals = solutions
+        .iter()
+        .filter_map(|solution| {
+            let input = source.read(*solution).ok()?;
+            let params = Params::resolve(solution.params(), &options.overrides());
+            let visual = match runner::catch(|| solution.visual(&input, &params)) {
+                Ok(visual) => visual?.unwrap_or_else(|err| format!("failed: {}", err)),
+                Err(message) => format!("failed: {}", message)
+            };
+            Some((solution.name(), visual))
+        })
+        .collect::<Vec<(String, String)>>();
+    let history = bench::history(bench::HISTORY)?;
+    let html = crate::report::html(solutions, runs, &visuals, &history);
+    fs::write(path, html).map_err(|err| format!("{}: {}", path, err))
+}
+
+// Print a part's answer (or error) in single-day mode, returns whether it failed
+fn report (solution: &dyn days::Solution, part: Part, result: Result<Result<error::Answer, error::SolveError>, String>, answers: &Answers) -> bool {
+    match result {
+        Ok(Ok(answer)) => {
+            println!("{}\n", answer);
+            if let Some(expected) = answers.get(&solution.name(), part).filter(|expected| *expected != answer) {
+                println!("Expected: {}\n", expected);
+            }
+            false
+        },
+        Ok(Err(err)) => {
+            eprintln!("Error: {}\n", err.at(solution, part));
+            true
+        },
+        Err(message) => {
+            eprintln!("Error: {}\n", runner::panicked(solution, part, &message));
+            true
+        }
+    }
+}
+
+// Same as `report` for a part run under `--timeout` or `--memory`
+fn report_run (run: runner::Run, answers: &Answers) -> bool {
+    match &run.memory {
+        Some(bytes) => println!("({:?}, {} peak)", run.duration, memory::format(*bytes)),
+        None => println!("({:?})", run.duration)
+    }
+    match run.status {
+        Status::Ok => {
+            println!("{}\n", run.answer);
+            if let Some(expected) = answers.get(&run.name, run.part).filter(|expected| *expected != run.answer) {
+                println!("Expected: {}\n", expected);
+            }
+            false
+        },
+        Status::Unimplemented => {
+            println!("unimplemented\n");
+            false
+        },
+        status => {
+            eprintln!("Error: {}\n", match status {
+                Status::Timeout(timeout) => format!("gave up after {:.2?}", timeout),
+                Status::Failed(err) => err,
+                status => status.to_string()
+            });
+            true
+        }
+    }
+}
+
+fn list_command () {
+    for solution in days::all() {
+        println!("{:>5}  {}", solution.name(), solution.title());
+        for param in solution.params() {
+            println!("       --param {}={}  {}", param.name, param.default, param.description);
+        }
+        for alternative in solution.alternatives() {
+            println!("       --impl {}  part {}: {}", alternative.name, alternative.part, alternative.description);
+        }
+    }
+}
+
+// Run several days (a range or a year), or check any selection against (or record it into) the stored answers
+fn days_command (options: &cli::Options, answers: Answers) -> Result<bool, String> {
+    let solutions = implementation(options, runner::select(&options.command)?)?;
+    let source = options.source()?;
+    params::check(&solutions, &options.params)?;
+    if solutions.len() > 1 && !source.per_day() {
+        return Err(format!("{} can only be used with a single day", source.describe(solutions[0])));
+    }
+    // Stored answers only hold for the default inputs and parameters, with rendered text read
+    let answers = if source == Source::Default && !options.raw && options.params.is_empty() { answers } else { Answers::default() };
+    let json = options.format == Format::Json;
+    if !json {
+        println!("{}", runner::header(options.memory));
+    }
+    let mut recorded = answers.clone();
+    // Cached runs have no rendering nor memory to show, and would skip the `--impl` implementation
+    let mut cache = if options.no_cache || options.raw || options.memory || options.implementation.is_some() { None } else { Some(cache::Cache::load(cache::PATH)) };
+    let start = Instant::now();
+    let runs = runner::run_all(&solutions, &source, options.jobs, options.limits(), &options.overrides(), cache.as_mut(), |run| {
+        if options.record && run.status == Status::Ok {
+            recorded.set(&run.name, run.part, &run.answer);
+        }
+        if options.verify {
+            answers::verify(run, &answers);
+        }
+        // JSON lines: one object per part
+        if json {
+            println!("{}", runner::json(run));
+        } else {
+            println!("{}", runner::row(run, options.memory));
+        }
+    });
+    if !json {
+        println!("\n{}", runner::totals(&runs, start.elapsed()));
+    }
+    if let Some(Err(err)) = cache.map(|cache| cache.save(cache::PATH)) {
+        eprintln!("{}", err);
+    }
+    if let Some(path) = &options.report {
+        match report_file(path, options, &solutions, &source, &runs) {
+            Ok(()) => eprintln!("Report written to {}", path),
+            Err(err) => eprintln!("{}", err)
+        }
+    }
+    if options.record {
+        recorded.save(answers::PATH)?;
+        eprintln!("Recorded answers to {}", answers::PATH);
+    }
+    Ok(!runs.iter().any(|run| matches!(run.status, Status::Failed(_) | Status::Wrong(_) | Status::Timeout(_))))
+}
+
+// Run both parts of a single day, printing its input first
+fn day_command (options: &cli::Options, answers: Answers) -> Result<bool, String> {
+    let solution = days::find(&options.command).ok_or("Please provide a valid day (see `list`)")?;
+    let solution = implementation(options, vec![solution])?[0];
+    let day = solution.name();
+
+    // Retrieve input, the default file is only read when no other input is given
+    let source = options.source()?;
+    let input = &Arc::new(source.read(solution)?);
+    let limits = options.limits();
+    params::check(&[solution], &options.params)?;
+    let params = &Params::resolve(solution.params(), &options.overrides());
+    // Stored answers only hold for the default input and parameters, with rendered text read
+    let answers = if source == Source::Default && !options.raw && options.params.is_empty() { answers } else { Answers::default() };
+
+    // Single JSON document with both parts, panics are reported as failed parts
+    if options.format == Format::Json {
+        let runs: Vec<runner::Run> = [Part::One, Part::Two]
+            .iter()
+            .map(|part| {
+                let mut run = runner::run_limited(solution, *part, input, params, limits);
+                if answers.get(&day, *part).is_some() {
+                    answers::verify(&mut run, &answers);
+                }
+                run
+            })
+            .collect();
+        println!(
+            "{{\"day\":{},\"title\":{},\"parts\":[{}]}}",
+            runner::json_string(&day),
+            runner::json_string(solution.title()),
+            runs.iter().map(runner::json).collect::<Vec<String>>().join(",")
+        );
+        return Ok(!runs.iter().any(|run| matches!(run.status, Status::Failed(_) | Status::Wrong(_) | Status::Timeout(_))));
+    }
+
+    if !options.quiet {
+        println!("{}", source.describe(solution));
+        println!("{}\n", input);
+    }
+
+    // Limited parts run on their own thread, reported once done (or given up on)
+    if limits != Limits::default() {
+        print!("Part1: ");
+        let run1 = runner::run_limited(solution, Part::One, input, params, limits);
+        let duration1 = run1.duration;
+        let failed1 = report_run(run1, &answers);
+
+        print!("Part2: ");
+        let run2 = runner::run_limited(solution, Part::Two, input, params, limits);
+        let duration2 = run2.duration;
+        let failed2 = report_run(run2, &answers);
+
+        println!("Time elapsed: {:?}", duration1 + duration2);
+        return Ok(!failed1 && !failed2);
+    }
+
+    // Part1
+    print!("Part1: ");
+    let start1 = Instant::now();
+    let result_part1 = runner::catch(|| solution.part1(input, params));
+    let duration1 = start1.elapsed();
+    println!("({:?})", duration1);
+    let failed1 = report(solution, Part::One, result_part1, &answers);
+
+    // Part2
+    print!("Part2: ");
+    let start2 = Instant::now();
+    let result_part2 = runner::catch(|| solution.part2(input, params));
+    let duration2 = start2.elapsed();
+    println!("({:?})", duration2);
+    let failed2 = report(solution, Part::Two, result_part2, &answers);
+
+    println!("Time elapsed: {:?}", duration1 + duration2);
+    Ok(!failed1 && !failed2)
+}
+
+// Everything but `image`, `new` and `watch`: the command of `options` is `list`, `readme`, a selection of days or a single day
+pub fn run (options: &cli::Options) -> Result<bool, String> {
+    if options.command == "list" {
+        text_only(options, "list")?;
+        list_command();
+        return Ok(true);
+    }
+    // Compare the implementations of the selected days
+    if options.cross_check {
+        text_only(options, "--cross-check")?;
+        return cross_check_command(options);
+    }
+    if let Some(iterations) = options.bench {
+        text_only(options, "--bench")?;
+        return bench_command(options, iterations);
+    }
+
+    let answers = Answers::load(answers::PATH)?;
+    // Progress table of the README
+    if options.command == "readme" {
+        text_only(options, "readme")?;
+        return readme_command(options, &answers).map(|_| true);
+    }
+    // The report is written from the runs of the table, a single day gets one too
+    if options.verify || options.record || options.report.is_some() || options.command == "all" || options.command.contains("..") || options.command.parse::<u16>().is_ok() {
+        return days_command(options, answers);
+    }
+    day_command(options, answers)
+}
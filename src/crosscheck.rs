@@ -0,0 +1,79 @@
+use crate::days::{self,Selected,Solution};
+use crate::params::Params;
+use crate::runner::{self,Part,Run,Status};
+
+// Run of one of the implementations of a part
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub implementation: &'static str,
+    pub run: Run
+}
+
+// Every implementation of `part` on the same input, the default one first
+pub fn cross_check (solution: &'static dyn Solution, part: Part, input: &str, params: &Params) -> Vec<Check> {
+    days::implementations(solution, part)
+        .into_iter()
+        .map(|implementation| {
+            let selected = Selected { solution, implementation: String::from(implementation) };
+            Check { implementation, run: runner::run_part(&selected, part, input, params) }
+        })
+        .collect()
+}
+
+// Helper
+fn outcome (run: &Run) -> String {
+    match &run.status {
+        Status::Failed(err) => format!("failed: {}", err),
+        _ => run.answer.trim_end().to_string()
+    }
+}
+
+// Implementations agree when they all give the same answer, or fail the same way
+pub fn agree (checks: &[Check]) -> bool {
+    checks.iter().all(|check| outcome(&check.run) == outcome(&checks[0].run))
+}
+
+// Table
+pub fn header () -> String {
+    format!("{:<6} {:<4} {:<14} {:<13} {:>12} {:>8}  {}", "Day", "Part", "Implementation", "Status", "Time", "Speed", "Answer")
+}
+
+// Speed relative to the first (default) implementation, e.g. `40.0x` for one 40 times faster
+pub fn row (check: &Check, reference: &Check) -> String {
+    let run = &check.run;
+    let speed = if run.duration.as_nanos() == 0 {
+        String::from("-")
+    } else {
+        format!("{:.2}x", reference.run.duration.as_secs_f64() / run.duration.as_secs_f64())
+    };
+    let answer = outcome(run).replace('\n', &format!("\n{:71}", ""));
+    format!(
+        "{:<6} {:<4} {:<14} {:<13} {:>12} {:>8}  {}",
+        run.name, run.part, check.implementation, run.status, format!("{:.2?}", run.duration), speed, answer
+    )
+        .trim_end()
+        .to_string()
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use crate::days;
+    use crate::params::Params;
+    use crate::runner::Part;
+
+    #[test]
+    fn crosscheck_agree () {
+        let day16 = days::find("day16").unwrap();
+        let checks = super::cross_check(day16, Part::One, "80871224585914546619083218645595", &Params::default());
+        assert_eq!(checks.iter().map(|check| check.implementation).collect::<Vec<_>>(), vec!["default", "prefix-sum"]);
+        assert!(super::agree(&checks));
+        assert!(super::row(&checks[1], &checks[0]).starts_with("day16  1    prefix-sum     ok"));
+        assert!(super::row(&checks[1], &checks[0]).ends_with("x  24176176"));
+
+        let mut disagreeing = checks.clone();
+        disagreeing[1].run.answer = String::from("24176177");
+        assert!(!super::agree(&disagreeing));
+        assert!(super::agree(&super::cross_check(day16, Part::One, "8x", &Params::default())));
+    }
+}
@@ -1,86 +1,253 @@
-mod day1;
-mod day2;
-mod day3;
-mod day4;
-mod day5;
-mod day6;
-mod day7;
-mod day8;
-mod day9;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day20;
-mod day21;
-mod day22;
-mod day23;
-mod day24;
-mod day25;
-
-pub fn part1 (day: &str, input: &str) -> String {
-    return match day {
-        "day1" => day1::part1(input),
-        "day2" => day2::part1(input),
-        "day3" => day3::part1(input),
-        "day4" => day4::part1(input),
-        "day5" => day5::part1(input),
-        "day6" => day6::part1(input),
-        "day7" => day7::part1(input),
-        "day8" => day8::part1(input),
-        "day9" => day9::part1(input),
-        "day10" => day10::part1(input),
-        "day11" => day11::part1(input),
-        "day12" => day12::part1(input),
-        "day13" => day13::part1(input),
-        "day14" => day14::part1(input),
-        "day15" => day15::part1(input),
-        "day16" => day16::part1(input),
-        "day17" => day17::part1(input),
-        "day18" => day18::part1(input),
-        "day19" => day19::part1(input),
-        "day20" => day20::part1(input),
-        "day21" => day21::part1(input),
-        "day22" => day22::part1(input),
-        "day23" => day23::part1(input),
-        "day24" => day24::part1(input),
-        "day25" => day25::part1(input),
-        _ => String::from("Invalid day")
+use crate::error::{Answer,SolveError};
+use crate::params::{Param,Params};
+use crate::runner::Part;
+
+// Name of the `part1`/`part2` methods among the implementations of a part
+pub const DEFAULT: &str = "default";
+
+// Another way of solving a part, run with `--impl name` or against the others with `--cross-check`
+pub struct Implementation {
+    pub name: &'static str,
+    pub part: Part,
+    pub description: &'static str,
+    pub run: fn (&str, &Params) -> Result<Answer, SolveError>
+}
+
+// Year of the days named without one, e.g. `day5`
+pub const DEFAULT_YEAR: u16 = 2019;
+
+// Days are shared between the worker threads of `--jobs`
+pub trait Solution: Sync {
+    fn year (&self) -> u16;
+    fn day (&self) -> u8;
+    fn title (&self) -> &'static str;
+    // `params` holds the values of the parameters the day declares
+    fn part1 (&self, input: &str, params: &Params) -> Result<Answer, SolveError>;
+    fn part2 (&self, input: &str, params: &Params) -> Result<Answer, SolveError>;
+
+    // Days of the default year keep their short name, the one stored answers, benchmarks and the cache use
+    fn name (&self) -> String {
+        name(self.year(), self.day())
+    }
+    // Constants that can be changed with `--param name=value`
+    fn params (&self) -> &'static [Param] {
+        &[]
+    }
+    // Alternatives to `part1` and `part2`, e.g. a naive solver kept next to the optimized one
+    fn alternatives (&self) -> &'static [Implementation] {
+        &[]
+    }
+    // Whether the runner normalizes the input (BOM, line endings, trailing whitespace), off for days where whitespace
+    // is significant, like day 20's space padded maze. Called directly, days still get the input as it is
+    fn normalized_input (&self) -> bool {
+        true
+    }
+    // Picture of the puzzle (an image, a map...) for the report, when the day draws one
+    fn visual (&self, _input: &str, _params: &Params) -> Option<Result<String, SolveError>> {
+        None
+    }
+    // Part of the cache key, to bump when a change outside the library (e.g. a dependency update) changes its answers
+    fn version (&self) -> &'static str {
+        "1"
+    }
+    // Placeholders not solved yet are skipped instead of reporting a bogus answer
+    fn implemented (&self) -> (bool, bool) {
+        (true, true)
+    }
+}
+
+// Every day module exposes a `Day` implementing `Solution`, grouped by year under src/days/yYYYY/: adding a day is adding it here
+macro_rules! register {
+    ($($year:ident { $($day:ident),* $(,)? }),* $(,)?) => {
+        $(
+            pub mod $year {
+                // What the days of the year import from `super`
+                #[allow(unused_imports)]
+                use super::{Implementation,Param,Params,Solution};
+                $(pub mod $day;)*
+            }
+        )*
+
+        pub fn all () -> Vec<&'static dyn Solution> {
+            vec![$($(&$year::$day::Day,)*)*]
+        }
+
+        // Source of each day, part of the cache key so that editing a day invalidates its cached answers
+        pub fn source (solution: &dyn Solution) -> &'static str {
+            match format!("y{}/day{}", solution.year(), solution.day()).as_str() {
+                $($(concat!(stringify!($year), "/", stringify!($day)) => include_str!(concat!("days/", stringify!($year), "/", stringify!($day), ".rs")),)*)*
+                _ => ""
+            }
+        }
+
+        // One test per day checking its answers against `inputs/answers.txt`
+        #[cfg(test)]
+        mod answers {
+            $(mod $year {
+                $(#[test] fn $day () { crate::answers::check(&crate::days::$year::$day::Day) })*
+            })*
+        }
+    };
+}
+
+// Library modules answers depend on besides the day's own source: those every day goes through,
+// and those included only in the days importing them
+const COMMON: &[&str] = &[include_str!("error.rs"), include_str!("params.rs")];
+const IMPORTED: &[(&str, &[&str])] = &[
+    ("crate::intcode", &[
+        include_str!("intcode.rs"),
+        include_str!("intcode/diff.rs"),
+        include_str!("intcode/hooks.rs"),
+        include_str!("intcode/image.rs"),
+        include_str!("intcode/loader.rs"),
+        include_str!("intcode/search.rs")
+    ]),
+    ("crate::ocr", &[include_str!("ocr.rs")])
+];
+
+// Sources an answer of the day depends on, part of the cache key so that fixing a shared module invalidates it too
+pub fn sources (solution: &dyn Solution) -> Vec<&'static str> {
+    let source = source(solution);
+    let imported = IMPORTED
+        .iter()
+        .filter(|(module, _)| source.contains(module))
+        .flat_map(|(_, files)| files.iter());
+    std::iter::once(&source).chain(COMMON).chain(imported).copied().collect()
+}
+
+register!(
+    y2019 {
+        day1, day2, day3, day4, day5, day6, day7, day8, day9, day10,
+        day11, day12, day13, day14, day15, day16, day17, day18, day19, day20,
+        day21, day22, day23, day24, day25,
+    },
+);
+
+// `dayN` for the default year, `YYYY/dayN` otherwise
+pub fn name (year: u16, day: u8) -> String {
+    if year == DEFAULT_YEAR {
+        format!("day{}", day)
+    } else {
+        format!("{}/day{}", year, day)
     }
 }
-pub fn part2 (day: &str, input: &str) -> String {
-    return match day {
-        "day1" => day1::part2(input),
-        "day2" => day2::part2(input),
-        "day3" => day3::part2(input),
-        "day4" => day4::part2(input),
-        "day5" => day5::part2(input),
-        "day6" => day6::part2(input),
-        "day7" => day7::part2(input),
-        "day8" => day8::part2(input),
-        "day9" => day9::part2(input),
-        "day10" => day10::part2(input),
-        "day11" => day11::part2(input),
-        "day12" => day12::part2(input),
-        "day13" => day13::part2(input),
-        "day14" => day14::part2(input),
-        "day15" => day15::part2(input),
-        "day16" => day16::part2(input),
-        "day17" => day17::part2(input),
-        "day18" => day18::part2(input),
-        "day19" => day19::part2(input),
-        "day20" => day20::part2(input),
-        "day21" => day21::part2(input),
-        "day22" => day22::part2(input),
-        "day23" => day23::part2(input),
-        "day24" => day24::part2(input),
-        "day25" => day25::part2(input),
-        _ => String::from("Invalid day")
+
+// `YYYY/dayN`, or `dayN` for a day of the default year
+pub fn parse_id (id: &str) -> Option<(u16, u8)> {
+    let (year, day) = match id.split_once('/') {
+        Some((year, day)) => (year.parse().ok()?, day),
+        None => (DEFAULT_YEAR, id)
+    };
+    let day = day.strip_prefix("day")?.parse().ok()?;
+    Some((year, day))
+}
+
+pub fn find (id: &str) -> Option<&'static dyn Solution> {
+    let (year, day) = parse_id(id)?;
+    all()
+        .into_iter()
+        .find(|solution| solution.year() == year && solution.day() == day)
+}
+
+// Names of the implementations of a part, the default one first
+pub fn implementations (solution: &dyn Solution, part: Part) -> Vec<&'static str> {
+    let alternatives = solution.alternatives().iter().filter(|alternative| alternative.part == part);
+    std::iter::once(DEFAULT).chain(alternatives.map(|alternative| alternative.name)).collect()
+}
+
+// A day whose parts are solved by `implementation` when they have one by that name, everything else is the day's
+pub struct Selected {
+    pub solution: &'static dyn Solution,
+    pub implementation: String
+}
+impl Selected {
+    fn alternative (&self, part: Part) -> Option<&'static Implementation> {
+        self.solution
+            .alternatives()
+            .iter()
+            .find(|alternative| alternative.part == part && alternative.name == self.implementation)
+    }
+}
+impl Solution for Selected {
+    fn year (&self) -> u16 { self.solution.year() }
+    fn day (&self) -> u8 { self.solution.day() }
+    fn title (&self) -> &'static str { self.solution.title() }
+    fn part1 (&self, input: &str, params: &Params) -> Result<Answer, SolveError> {
+        match self.alternative(Part::One) {
+            Some(alternative) => (alternative.run)(input, params),
+            None => self.solution.part1(input, params)
+        }
+    }
+    fn part2 (&self, input: &str, params: &Params) -> Result<Answer, SolveError> {
+        match self.alternative(Part::Two) {
+            Some(alternative) => (alternative.run)(input, params),
+            None => self.solution.part2(input, params)
+        }
+    }
+    fn name (&self) -> String { self.solution.name() }
+    fn params (&self) -> &'static [Param] { self.solution.params() }
+    fn alternatives (&self) -> &'static [Implementation] { self.solution.alternatives() }
+    fn normalized_input (&self) -> bool { self.solution.normalized_input() }
+    fn visual (&self, input: &str, params: &Params) -> Option<Result<String, SolveError>> { self.solution.visual(input, params) }
+    fn version (&self) -> &'static str { self.solution.version() }
+    fn implemented (&self) -> (bool, bool) { self.solution.implemented() }
+}
+
+// `--impl` has to name an implementation of one of the selected days
+pub fn check (solutions: &[&'static dyn Solution], implementation: &str) -> Result<(), String> {
+    if implementation == DEFAULT || solutions.iter().any(|solution| solution.alternatives().iter().any(|alternative| alternative.name == implementation)) {
+        Ok(())
+    } else {
+        Err(format!("Unknown implementation {} (see `list`)", implementation))
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use crate::params::Params;
+    use crate::runner::Part;
+    use super::Solution;
+
+    #[test]
+    fn days_find () {
+        assert_eq!(super::parse_id("day5"), Some((2019, 5)));
+        assert_eq!(super::parse_id("2019/day5"), Some((2019, 5)));
+        assert_eq!(super::parse_id("2020/day25"), Some((2020, 25)));
+        assert_eq!(super::parse_id("2019/5"), None);
+        assert_eq!(super::parse_id("5"), None);
+        assert_eq!(super::find("2019/day5").map(|solution| solution.name()), Some(String::from("day5")));
+        assert!(super::find("2020/day5").is_none());
+        assert!(super::all().iter().all(|solution| !super::source(*solution).is_empty()));
+    }
+
+    #[test]
+    fn days_sources () {
+        let intcode = include_str!("intcode/search.rs");
+        let ocr = include_str!("ocr.rs");
+        let sources = |id| super::sources(super::find(id).unwrap());
+        assert_eq!(sources("day1").len(), 3);
+        assert!(!sources("day1").contains(&intcode));
+        assert!(sources("day15").contains(&intcode));
+        assert!(sources("day11").contains(&intcode) && sources("day11").contains(&ocr));
+        assert!(sources("day8").contains(&ocr) && !sources("day8").contains(&intcode));
+    }
+
+    #[test]
+    fn days_implementations () {
+        let day1 = super::find("day1").unwrap();
+        let day16 = super::find("day16").unwrap();
+        assert_eq!(super::implementations(day1, Part::One), vec!["default"]);
+        assert_eq!(super::implementations(day16, Part::One), vec!["default", "prefix-sum"]);
+        assert_eq!(super::implementations(day16, Part::Two), vec!["default"]);
+        assert!(super::check(&[day1, day16], "prefix-sum").is_ok());
+        assert!(super::check(&[day1], "default").is_ok());
+        assert!(super::check(&[day1], "prefix-sum").is_err());
+
+        let selected = super::Selected { solution: day16, implementation: String::from("prefix-sum") };
+        assert_eq!(selected.name(), "day16");
+        let signal = "80871224585914546619083218645595";
+        assert_eq!(selected.part1(signal, &Params::default()).unwrap(), "24176176");
+        assert_eq!(selected.part2("03036732577212944063491565474664", &Params::default()).unwrap(), "84462026");
     }
 }
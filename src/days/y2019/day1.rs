@@ -0,0 +1,72 @@
+use crate::error::{self,Answer,SolveError};
+use super::{Params,Solution};
+
+// Helper
+fn read_input (input: &str) -> Result<Vec<i64>, SolveError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, s)| error::number(s.trim(), index + 1, 1))
+        .collect()
+}
+
+// Part1
+pub fn part1 (input: &str) -> Result<Answer, SolveError> {
+    Ok(format!("{:?}",
+        read_input(input)?
+            .iter()
+            .map(|i: &i64| i / 3 - 2)
+            .fold(0i64, |a, b| { a + b })
+    ))
+}
+
+// Part2
+pub fn part2 (input: &str) -> Result<Answer, SolveError> {
+    let fuel = read_input(input)?
+        .iter()
+        .map(|weight: &i64| {
+            let mut i = *weight;
+            let mut fuel = 0;
+            i = i / 3 - 2;
+            while i > 0 {
+                fuel += i;
+                i = i / 3 - 2;
+            }
+            fuel
+        })
+        .fold(0i64, |a, b| { a + b });
+
+    Ok(format!("{}", fuel))
+}
+
+// Solution
+pub struct Day;
+impl Solution for Day {
+    fn year (&self) -> u16 { 2019 }
+    fn day (&self) -> u8 { 1 }
+    fn title (&self) -> &'static str { "The Tyranny of the Rocket Equation" }
+    fn part1 (&self, input: &str, _params: &Params) -> Result<Answer, SolveError> { part1(input) }
+    fn part2 (&self, input: &str, _params: &Params) -> Result<Answer, SolveError> { part2(input) }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn day1_part1 () {
+        assert_eq!(super::part1("12").unwrap(), "2");
+        assert_eq!(super::part1("13").unwrap(), "2");
+        assert_eq!(super::part1("14").unwrap(), "2");
+        assert_eq!(super::part1("15").unwrap(), "3");
+        assert_eq!(super::part1("1969").unwrap(), "654");
+        assert_eq!(super::part1("100756").unwrap(), "33583");
+        assert_eq!(super::part1("12\n14").unwrap(), "4");
+    }
+
+    #[test]
+    fn day1_part2 () {
+        assert_eq!(super::part2("14").unwrap(), "2");
+        assert_eq!(super::part2("1969").unwrap(), "966");
+        assert_eq!(super::part2("100756").unwrap(), "50346");
+    }
+}
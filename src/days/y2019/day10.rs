@@ -3,6 +3,8 @@ use itertools::Itertools;
 use num::Complex;
 use num::integer::gcd;
 use std::cmp::Ordering;
+use crate::error::{Answer,SolveError};
+use super::{Params,Solution};
 
 // Helper
 type Coord = Complex<i64>;
@@ -28,17 +30,19 @@ impl PartialEq for Angle {
 }
 impl Eq for Angle { }
 
-fn read_input (input: &str) -> Coords {
+fn read_input (input: &str) -> Result<Coords, SolveError> {
     let mut out = Vec::new();
-    for (y, line) in input.trim().split("\n").enumerate() {
-        for (x, cell) in line.chars().enumerate() {
+    // `lines` and `trim_end` drop `\r\n` line endings, the BOM an editor may add has to go too
+    for (y, line) in input.trim_start_matches('\u{feff}').trim().lines().enumerate() {
+        for (x, cell) in line.trim_end().chars().enumerate() {
             match cell {
                 '#' => out.push(Complex::new(x as i64, y as i64)),
-                _ => {},
+                '.' => {},
+                c => return Err(SolveError::parse(y + 1, x + 1, format!("unknown cell {:?}", c)))
             }
         }
     }
-    out
+    Ok(out)
 }
 fn get_best_asteroid (asteroids: &Coords) -> (i64, Coord) {
     let mut asteroids_set = HashSet::new();
@@ -75,15 +79,15 @@ fn get_best_asteroid (asteroids: &Coords) -> (i64, Coord) {
 }
 
 // Part1
-pub fn part1 (input: &str) -> String {
-    let asteroids = read_input(input);
+pub fn part1 (input: &str) -> Result<Answer, SolveError> {
+    let asteroids = read_input(input)?;
     let (max, _) = get_best_asteroid(&asteroids);
-    format!("{}", max)
+    Ok(format!("{}", max))
 }
 
 // Part2
-pub fn part2 (input: &str) -> String {
-    let asteroids = read_input(input);
+pub fn part2 (input: &str) -> Result<Answer, SolveError> {
+    let asteroids = read_input(input)?;
     let (_, base) = get_best_asteroid(&asteroids);
 
     // Building Btree indexed by "angle", sorted clockwise from UP
@@ -118,45 +122,64 @@ pub fn part2 (input: &str) -> String {
             None => { relative_positions.remove(index); },
             Some((_, coord)) => {
                 if i == 199 {
-                    return format!("{}", 100 * coord.re + coord.im);
+                    return Ok(format!("{}", 100 * coord.re + coord.im));
                 }
                 i += 1;
             }
         }
 
     }
-    format!("{}", -1)
+    Err(SolveError::unsolvable("fewer than 200 asteroids can be vaporized"))
+}
+
+// Solution
+pub struct Day;
+impl Solution for Day {
+    fn year (&self) -> u16 { 2019 }
+    fn day (&self) -> u8 { 10 }
+    fn title (&self) -> &'static str { "Monitoring Station" }
+    fn part1 (&self, input: &str, _params: &Params) -> Result<Answer, SolveError> { part1(input) }
+    fn part2 (&self, input: &str, _params: &Params) -> Result<Answer, SolveError> { part2(input) }
 }
 
 // Tests
 #[cfg(test)]
 mod tests {
+    use crate::days::Solution;
+    use crate::params::Params;
+
     #[test]
     fn day10_part1 () {
         assert_eq!(
-            super::part1(".#..#\n.....\n#####\n....#\n...##"),
+            super::part1(".#..#\n.....\n#####\n....#\n...##").unwrap(),
             "8"
         );
         assert_eq!(
-            super::part1("......#.#.\n#..#.#....\n..#######.\n.#.#.###..\n.#..#.....\n..#....#.#\n#..#....#.\n.##.#..###\n##...#..#.\n.#....####"),
+            super::part1("......#.#.\n#..#.#....\n..#######.\n.#.#.###..\n.#..#.....\n..#....#.#\n#..#....#.\n.##.#..###\n##...#..#.\n.#....####").unwrap(),
             "33"
         );
         assert_eq!(
-            super::part1("#.#...#.#.\n.###....#.\n.#....#...\n##.#.#.#.#\n....#.#.#.\n.##..###.#\n..#...##..\n..##....##\n......#...\n.####.###."),
+            super::part1("#.#...#.#.\n.###....#.\n.#....#...\n##.#.#.#.#\n....#.#.#.\n.##..###.#\n..#...##..\n..##....##\n......#...\n.####.###.").unwrap(),
             "35"
         );
         assert_eq!(
-            super::part1(".#..#..###\n####.###.#\n....###.#.\n..###.##.#\n##.##.#.#.\n....###..#\n..#.#..#.#\n#..#.#.###\n.##...##.#\n.....#.#.."),
+            super::part1(".#..#..###\n####.###.#\n....###.#.\n..###.##.#\n##.##.#.#.\n....###..#\n..#.#..#.#\n#..#.#.###\n.##...##.#\n.....#.#..").unwrap(),
             "41"
         );
         assert_eq!(
-            super::part1(".#..##.###...#######\n##.############..##.\n.#.######.########.#\n.###.#######.####.#.\n#####.##.#.##.###.##\n..#####..#.#########\n####################\n#.####....###.#.#.##\n##.#################\n#####.##.###..####..\n..######..##.#######\n####.##.####...##..#\n.#####..#.######.###\n##...#.##########...\n#.##########.#######\n.####.#.###.###.#.##\n....##.##.###..#####\n.#.#.###########.###\n#.#.#.#####.####.###\n###.##.####.##.#..##"),
+            super::part1(".#..##.###...#######\n##.############..##.\n.#.######.########.#\n.###.#######.####.#.\n#####.##.#.##.###.##\n..#####..#.#########\n####################\n#.####....###.#.#.##\n##.#################\n#####.##.###..####..\n..######..##.#######\n####.##.####...##..#\n.#####..#.######.###\n##...#.##########...\n#.##########.#######\n.####.#.###.###.#.##\n....##.##.###..#####\n.#.#.###########.###\n#.#.#.#####.####.###\n###.##.####.##.#..##").unwrap(),
             "210"
         );
     }
 
     #[test]
     fn day10_part2 () {
-        assert_eq!(super::part2(".#..##.###...#######\n##.############..##.\n.#.######.########.#\n.###.#######.####.#.\n#####.##.#.##.###.##\n..#####..#.#########\n####################\n#.####....###.#.#.##\n##.#################\n#####.##.###..####..\n..######..##.#######\n####.##.####...##..#\n.#####..#.######.###\n##...#.##########...\n#.##########.#######\n.####.#.###.###.#.##\n....##.##.###..#####\n.#.#.###########.###\n#.#.#.#####.####.###\n###.##.####.##.#..##"), "802");
+        assert_eq!(super::part2(".#..##.###...#######\n##.############..##.\n.#.######.########.#\n.###.#######.####.#.\n#####.##.#.##.###.##\n..#####..#.#########\n####################\n#.####....###.#.#.##\n##.#################\n#####.##.###..####..\n..######..##.#######\n####.##.####...##..#\n.#####..#.######.###\n##...#.##########...\n#.##########.#######\n.####.#.###.###.#.##\n....##.##.###..#####\n.#.#.###########.###\n#.#.#.#####.####.###\n###.##.####.##.#..##").unwrap(), "802");
+    }
+
+    #[test]
+    fn day10_crlf_bom () {
+        // Called directly, without the normalization of the runner
+        assert_eq!(super::Day.part1("\u{feff}.#..#\r\n.....\r\n#####\r\n....#\r\n...##\r\n", &Params::default()).unwrap(), "8");
     }
 }
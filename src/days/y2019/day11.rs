@@ -1,21 +1,13 @@
 use std::collections::HashMap;
 use num::Complex;
-use crate::intcode::{Opcodes,Status,Machine};
-
-// Helper
-fn read_input (input: &str) -> Opcodes {
-    input
-        .lines()
-        .collect::<Vec<&str>>()
-        .join("")
-        .split(",")
-        .map(|s| s.trim().parse().expect("Cannot parse int"))
-        .collect()
-}
+use crate::intcode::{self,Status,Machine};
+use crate::error::{Answer,SolveError};
+use crate::ocr;
+use super::{Param,Params,Solution};
 
 // Part1
-pub fn part1 (input: &str) -> String {
-    let opcodes = read_input(input);
+pub fn part1 (input: &str) -> Result<Answer, SolveError> {
+    let opcodes = intcode::parse(input)?;
     let mut machine = Machine::new(&opcodes);
     let mut map = HashMap::<Complex<i64>, i64>::new();
     let mut pos = Complex::new(0, 0);
@@ -37,19 +29,21 @@ pub fn part1 (input: &str) -> String {
                 },
                 Status::Halt      => break 'main_loop,
                 Status::Ok        => {},
-                err               => panic!("Received a state {:?}", err)
+                Status::Error(op) => return Err(SolveError::fault(op)),
+                status            => return Err(SolveError::intcode(format!("unexpected state {:?}", status)))
             }
         }
         map.insert(pos.clone(), color.unwrap());
         orientation *= if rotation.unwrap() == 1 { Complex::new(0, 1) } else { Complex::new(0, -1) };
         pos += orientation;
     }
-    format!("{}", map.len())
+    Ok(format!("{}", map.len()))
 }
 
 // Part2
-pub fn part2 (input: &str) -> String {
-    let opcodes = read_input(input);
+// Rendering of the painted panels, starting on a white one
+fn render (input: &str) -> Result<String, SolveError> {
+    let opcodes = intcode::parse(input)?;
     let mut machine = Machine::new(&opcodes);
     let mut map = HashMap::<Complex<i64>, i64>::new();
     let mut pos = Complex::new(0, 0);
@@ -72,7 +66,8 @@ pub fn part2 (input: &str) -> String {
                 },
                 Status::Halt      => break 'main_loop,
                 Status::Ok        => {},
-                err               => panic!("Received a state {:?}", err)
+                Status::Error(op) => return Err(SolveError::fault(op)),
+                status            => return Err(SolveError::intcode(format!("unexpected state {:?}", status)))
             }
         }
         map.insert(pos.clone(), color.unwrap());
@@ -98,7 +93,25 @@ pub fn part2 (input: &str) -> String {
         }
         out += "\n";
     }
-    format!("{}", out)
+    Ok(out)
+}
+
+pub fn part2 (input: &str, raw: bool) -> Result<Answer, SolveError> {
+    ocr::answer(render(input)?, raw)
+}
+
+// Solution
+const PARAMS: &[Param] = &[ocr::RAW];
+
+pub struct Day;
+impl Solution for Day {
+    fn year (&self) -> u16 { 2019 }
+    fn day (&self) -> u8 { 11 }
+    fn title (&self) -> &'static str { "Space Police" }
+    fn part1 (&self, input: &str, _params: &Params) -> Result<Answer, SolveError> { part1(input) }
+    fn part2 (&self, input: &str, params: &Params) -> Result<Answer, SolveError> { part2(input, ocr::raw(params)?) }
+    fn params (&self) -> &'static [Param] { PARAMS }
+    fn visual (&self, input: &str, _params: &Params) -> Option<Result<String, SolveError>> { Some(render(input)) }
 }
 
 // Tests
@@ -121,12 +134,12 @@ mod tests {
                 104, 1, 104, 0,
                 3, 98,
                 104, 1, 104, 0,
-                99"), "6");
+                99").unwrap(), "6");
     }
 
     #[test]
     fn day11_part2 () {
-        assert_eq!(super::part2("
+        assert_eq!(super::render("
                 3, 98,
                 104, 1, 104, 0,
                 3, 98,
@@ -141,6 +154,6 @@ mod tests {
                 104, 1, 104, 0,
                 3, 98,
                 104, 1, 104, 0,
-                99"), "  #\n  #\n## \n");
+                99").unwrap(), "  #\n  #\n## \n");
     }
 }
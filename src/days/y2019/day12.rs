@@ -0,0 +1,145 @@
+use regex::Regex;
+use itertools::Itertools;
+use num::integer::lcm;
+use crate::error::{self,Answer,SolveError};
+use super::{Param,Params,Solution};
+
+// Helper
+fn read_input (input: &str, count: usize) -> Result<Vec<Vec<i64>>, SolveError> {
+    let regex = Regex::new(r"-?\d+").unwrap();
+    let moons = input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let coord = regex
+                .find_iter(line)
+                .take(3)
+                .map(|m| error::number(m.as_str(), index + 1, m.start() + 1))
+                .collect::<Result<Vec<i64>, SolveError>>()?;
+            if coord.len() != 3 {
+                return Err(SolveError::parse(index + 1, 1, format!("expected 3 coordinates, found {:?}", line)));
+            }
+            Ok(coord)
+        })
+        .collect::<Result<Vec<Vec<i64>>, SolveError>>()?;
+    if moons.len() != count {
+        return Err(SolveError::unsolvable(format!("expected {} moons, found {}", count, moons.len())));
+    }
+    Ok(moons)
+}
+fn step (moons: &mut Vec<Vec<i64>>, velocities: &mut Vec<Vec<i64>>) {
+    for perm in (0..moons.len()).combinations(2) {
+        let [i1, i2] = [perm[0], perm[1]];
+        for coord in 0..3 {
+            let coord1 = moons[i1][coord];
+            let coord2 = moons[i2][coord];
+            if coord1 < coord2 {
+                velocities[i1][coord] += 1; velocities[i2][coord] -= 1;
+            } else if coord1 > coord2 {
+                velocities[i1][coord] -= 1; velocities[i2][coord] += 1;
+            }
+        }
+    }
+
+    for i in 0..moons.len() {
+        for coord in 0..3 {
+            moons[i][coord] += velocities[i][coord];
+        }
+    }
+}
+fn run_for (mut moons: Vec<Vec<i64>>, n: i64) -> String {
+    let mut velocities = vec![vec![0, 0, 0]; moons.len()];
+    for _ in 0..n {
+        step(&mut moons, &mut velocities);
+    }
+
+    let mut energy: i64 = 0;
+    for i in 0..moons.len() {
+        let mut kin = 0i64;
+        let mut pot = 0i64;
+        for j in 0..3 {
+            kin += velocities[i][j].abs();
+            pot += moons[i][j].abs();
+        }
+        energy += kin * pot;
+    }
+    format!("{}", energy)
+}
+
+// Part1
+pub fn part1 (input: &str, steps: i64, count: usize) -> Result<Answer, SolveError> {
+    let moons = read_input(input, count)?;
+    Ok(run_for(moons, steps))
+}
+
+// Part2
+pub fn part2 (input: &str, count: usize) -> Result<Answer, SolveError> {
+    let mut moons = read_input(input, count)?;
+    let initial_positions = moons.clone();
+    let mut velocities = vec![vec![0, 0, 0]; count];
+    let mut periods: Vec<Option<i64>> = vec![None, None, None];
+    let mut iteration = 0;
+    while !periods.iter().all(|x| x.is_some()) {
+        step(&mut moons, &mut velocities);
+        iteration += 1;
+        for axis in 0..3 {
+            if periods[axis].is_none() {
+                let loop_detected = (0..count)
+                    .into_iter()
+                    .all(|i| moons[i][axis] == initial_positions[i][axis] && velocities[i][axis] == 0);
+                if loop_detected {
+                    periods[axis] = Some(iteration);
+                }
+            }
+        }
+    }
+    let out = lcm(lcm(periods[0].unwrap(), periods[1].unwrap()), periods[2].unwrap());
+    Ok(format!("{}", out))
+}
+
+// Solution
+const PARAMS: &[Param] = &[
+    Param { name: "steps", default: 1000, description: "Steps simulated before measuring the energy" },
+    Param { name: "moons", default: 4, description: "Number of moons in the input" }
+];
+
+pub struct Day;
+impl Solution for Day {
+    fn year (&self) -> u16 { 2019 }
+    fn day (&self) -> u8 { 12 }
+    fn title (&self) -> &'static str { "The N-Body Problem" }
+    fn part1 (&self, input: &str, params: &Params) -> Result<Answer, SolveError> { part1(input, params.get("steps")?, params.get("moons")?) }
+    fn part2 (&self, input: &str, params: &Params) -> Result<Answer, SolveError> { part2(input, params.get("moons")?) }
+    fn params (&self) -> &'static [Param] { PARAMS }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn day12_part1 () {
+        assert_eq!(super::run_for(super::read_input("<x=-1, y=0, z=2>
+<x=2, y=-10, z=-7>
+<x=4, y=-8, z=8>
+<x=3, y=5, z=-1>", 4).unwrap(), 10), "179");
+        assert_eq!(super::run_for(super::read_input("<x=-8, y=-10, z=0>
+<x=5, y=5, z=10>
+<x=2, y=-7, z=3>
+<x=9, y=-8, z=-3>", 4).unwrap(), 100), "1940");
+        assert_eq!(super::part1("<x=-1, y=0, z=2>
+<x=2, y=-10, z=-7>", 1, 2).unwrap(), "54");
+        assert!(super::part1("<x=-1, y=0, z=2>", 10, 4).is_err());
+    }
+
+    #[test]
+    fn day12_part2 () {
+        assert_eq!(super::part2("<x=-1, y=0, z=2>
+<x=2, y=-10, z=-7>
+<x=4, y=-8, z=8>
+<x=3, y=5, z=-1>", 4).unwrap(), "2772");
+        assert_eq!(super::part2("<x=-8, y=-10, z=0>
+<x=5, y=5, z=10>
+<x=2, y=-7, z=3>
+<x=9, y=-8, z=-3>", 4).unwrap(), "4686774924");
+    }
+}
@@ -1,6 +1,8 @@
 use text_io::*;
-use crate::intcode::{Opcodes,Status,Machine};
+use crate::intcode::{self,Status,Machine};
 use std::collections::HashMap;
+use crate::error::{Answer,SolveError};
+use super::{Params,Solution};
 
 // Helper
 enum Tile {
@@ -10,15 +12,6 @@ enum Tile {
     Paddle,
     Ball
 }
-fn read_input (input: &str) -> Opcodes {
-    input
-        .lines()
-        .collect::<Vec<&str>>()
-        .join("")
-        .split(",")
-        .map(|s| s.trim().parse().expect("Cannot parse int"))
-        .collect()
-}
 struct Pong {
     pub blocks: HashMap<(i64,i64), Tile>,
     pub score: i64,
@@ -39,7 +32,7 @@ impl Pong {
         }
     }
 
-    pub fn parse_output (self: &mut Self, machine: &mut Machine) -> Status {
+    pub fn parse_output (self: &mut Self, machine: &mut Machine) -> Result<Status, SolveError> {
         loop {
             match machine.step() {
                 Status::Output(o) => {
@@ -57,7 +50,7 @@ impl Pong {
                             2 => Tile::Block,
                             3 => Tile::Paddle,
                             4 => Tile::Ball,
-                            tile => panic!("Unknown tile: {}", tile)
+                            tile => return Err(SolveError::intcode(format!("unknown tile {}", tile)))
                         };
                         let x = self.current_pos.0.unwrap();
                         let y = self.current_pos.1.unwrap();
@@ -76,8 +69,9 @@ impl Pong {
                         self.current_pos = (None, None);
                     }
                 },
-                Status::Halt => return Status::Halt,
-                Status::WaitingForInput => return Status::WaitingForInput,
+                Status::Halt => return Ok(Status::Halt),
+                Status::WaitingForInput => return Ok(Status::WaitingForInput),
+                Status::Error(opcode) => return Err(SolveError::fault(opcode)),
                 _ => {}
             }
         }
@@ -112,27 +106,30 @@ impl Pong {
 }
 
 // Part1
-pub fn part1 (input: &str) -> String {
-    let opcodes = read_input(input);
+pub fn part1 (input: &str) -> Result<Answer, SolveError> {
+    let opcodes = intcode::parse(input)?;
     let mut machine = Machine::new(&opcodes);
     let mut pong = Pong::new();
-    pong.parse_output(&mut machine);
+    pong.parse_output(&mut machine)?;
     let nb_blocks = pong
         .blocks
         .values()
         .filter(|x| match x { Tile::Block => true, _ => false })
         .count();
-    format!("{}", nb_blocks)
+    Ok(format!("{}", nb_blocks))
 }
 
 // Part2
-pub fn part2 (input: &str) -> String {
-    let opcodes = read_input(input);
+pub fn part2 (input: &str) -> Result<Answer, SolveError> {
+    let opcodes = intcode::parse(input)?;
+    if opcodes.is_empty() {
+        return Err(SolveError::unsolvable("program too short to insert quarters"));
+    }
     let mut machine = Machine::new(&opcodes);
     machine.opcodes[0] = 2;
     let mut pong = Pong::new();
     loop {
-        let status = pong.parse_output(&mut machine);
+        let status = pong.parse_output(&mut machine)?;
         // pong.display();
         match status {
             Status::WaitingForInput => {
@@ -150,7 +147,17 @@ pub fn part2 (input: &str) -> String {
             _ => break
         }
     }
-    format!("{}", pong.score)
+    Ok(format!("{}", pong.score))
+}
+
+// Solution
+pub struct Day;
+impl Solution for Day {
+    fn year (&self) -> u16 { 2019 }
+    fn day (&self) -> u8 { 13 }
+    fn title (&self) -> &'static str { "Care Package" }
+    fn part1 (&self, input: &str, _params: &Params) -> Result<Answer, SolveError> { part1(input) }
+    fn part2 (&self, input: &str, _params: &Params) -> Result<Answer, SolveError> { part2(input) }
 }
 
 // Tests
@@ -158,11 +165,15 @@ pub fn part2 (input: &str) -> String {
 mod tests {
     #[test]
     fn day13_part1 () {
-        assert_eq!(super::part1("0"), "0");
+        // Two blocks and a wall
+        assert_eq!(super::part1("104,0,104,0,104,2,104,1,104,0,104,2,104,2,104,0,104,1,99").unwrap(), "2");
+        assert!(super::part1("0").is_err());
     }
 
     #[test]
     fn day13_part2 () {
-        assert_eq!(super::part2("0"), "0");
+        // With the quarters, the first instruction multiplies, then the game reports a score of 42
+        assert_eq!(super::part2("1,0,0,3,104,-1,104,0,104,42,99").unwrap(), "42");
+        assert!(super::part2("").is_err());
     }
 }
@@ -1,4 +1,6 @@
 use std::collections::HashMap;
+use crate::error::{self,Answer,SolveError};
+use super::{Param,Params,Solution};
 
 type Quantity = (i64, String);
 type Quantities = Vec<Quantity>;
@@ -25,7 +27,7 @@ impl RecipeBook {
         }
     }
 
-    fn run (self: &mut Self, nb_fuel: i64) -> &Self{
+    fn run (self: &mut Self, nb_fuel: i64) -> Result<&Self, SolveError> {
         let mut needed = Vec::new();
         needed.push((nb_fuel, "FUEL"));
 
@@ -45,7 +47,10 @@ impl RecipeBook {
                     self.ore_needed += quantity_to_create;
                     continue;
                 }
-                let (batch_size, materials_needed) = self.recipes.get(material_to_create).expect("Recipe not found");
+                let (batch_size, materials_needed) = self
+                    .recipes
+                    .get(material_to_create)
+                    .ok_or(SolveError::unsolvable(format!("no recipe produces {}", material_to_create)))?;
 
                 let nb_batch: i64 = (quantity_to_create - 1) / batch_size + 1;
                 let leftovers = nb_batch * batch_size - quantity_to_create;
@@ -59,64 +64,87 @@ impl RecipeBook {
             }
         }
 
-        self
+        Ok(self)
     }
 }
 
 // Helper
-fn read_quantities (s: &str) -> Recipe {
-    let values: Vec<Quantities> = s
+fn read_quantities (line: &str, index: usize) -> Result<Recipe, SolveError> {
+    // Tokens are slices of `line`, their offset gives the column
+    let column = |token: &str| token.as_ptr() as usize - line.as_ptr() as usize + 1;
+    let invalid = |token: &str| SolveError::parse(index + 1, column(token), format!("expected `QUANTITY MATERIAL`, found {:?}", token));
+    let values: Vec<Quantities> = line
         .split(" => ")
-        .map(|s| -> Quantities {
+        .map(|s| -> Result<Quantities, SolveError> {
             s.split(",")
-                .map(|s| -> Quantity {
+                .map(|s| -> Result<Quantity, SolveError> {
                     let params: Vec<&str> = s.trim().split(" ").collect();
-                    let value: i64 = params[0].trim().parse().expect("To be an i64");
+                    if params.len() != 2 {
+                        return Err(invalid(s));
+                    }
+                    let value: i64 = error::number(params[0], index + 1, column(params[0]))?;
                     let material = params[1].trim();
-                    (value, String::from(material))
+                    Ok((value, String::from(material)))
                 })
             .collect()
         })
-        .collect();
-    let from = values[0].clone();
-    let to = values[1][0].clone();
-    (from, to.0, to.1)
+        .collect::<Result<Vec<Quantities>, SolveError>>()?;
+    match &values[..] {
+        [from, to] if to.len() == 1 => Ok((from.clone(), to[0].0, to[0].1.clone())),
+        _ => Err(SolveError::parse(index + 1, 1, format!("expected `INPUTS => OUTPUT`, found {:?}", line)))
+    }
 }
 
-fn read_input (input: &str) -> Vec<Recipe> {
+fn read_input (input: &str) -> Result<Vec<Recipe>, SolveError> {
     input
         .lines()
-        .map(read_quantities)
+        .enumerate()
+        .map(|(index, line)| read_quantities(line, index))
         .collect()
 }
 
 // Part1
-pub fn part1 (input: &str) -> String {
-    let recipes = read_input(input);
+pub fn part1 (input: &str) -> Result<Answer, SolveError> {
+    let recipes = read_input(input)?;
     let mut recipe_book = RecipeBook::new(recipes);
-    recipe_book.run(1);
-    format!("{}", recipe_book.ore_needed)
+    recipe_book.run(1)?;
+    Ok(format!("{}", recipe_book.ore_needed))
 }
 
 // Part2
-pub fn part2 (input: &str) -> String {
-    let recipes = read_input(input);
+pub fn part2 (input: &str, ore: i64) -> Result<Answer, SolveError> {
+    let recipes = read_input(input)?;
     let mut recipe_book = RecipeBook::new(recipes);
-    let cost_for_1_fuel = recipe_book.clone().run(1).ore_needed;
-    let mut leftovers_ore = 1_000_000_000_000;
+    let cost_for_1_fuel = recipe_book.clone().run(1)?.ore_needed;
+    let mut leftovers_ore = ore;
     let mut nb_fuel = 0;
     loop {
         let estimation = (leftovers_ore / cost_for_1_fuel).max(1);
 
-        recipe_book.run(estimation);
+        recipe_book.run(estimation)?;
         let reality = recipe_book.ore_needed;
 
-        if reality > 1_000_000_000_000 { break }
+        if reality > ore { break }
 
         nb_fuel += estimation;
-        leftovers_ore = 1_000_000_000_000 - reality;
+        leftovers_ore = ore - reality;
     }
-    format!("{}", nb_fuel)
+    Ok(format!("{}", nb_fuel))
+}
+
+// Solution
+const PARAMS: &[Param] = &[
+    Param { name: "ore", default: 1_000_000_000_000, description: "Ore available to produce fuel" }
+];
+
+pub struct Day;
+impl Solution for Day {
+    fn year (&self) -> u16 { 2019 }
+    fn day (&self) -> u8 { 14 }
+    fn title (&self) -> &'static str { "Space Stoichiometry" }
+    fn part1 (&self, input: &str, _params: &Params) -> Result<Answer, SolveError> { part1(input) }
+    fn part2 (&self, input: &str, params: &Params) -> Result<Answer, SolveError> { part2(input, params.get("ore")?) }
+    fn params (&self) -> &'static [Param] { PARAMS }
 }
 
 // Tests
@@ -129,14 +157,14 @@ mod tests {
 7 A, 1 B => 1 C
 7 A, 1 C => 1 D
 7 A, 1 D => 1 E
-7 A, 1 E => 1 FUEL"), "31");
+7 A, 1 E => 1 FUEL").unwrap(), "31");
         assert_eq!(super::part1("9 ORE => 2 A
 8 ORE => 3 B
 7 ORE => 5 C
 3 A, 4 B => 1 AB
 5 B, 7 C => 1 BC
 4 C, 1 A => 1 CA
-2 AB, 3 BC, 4 CA => 1 FUEL"), "165");
+2 AB, 3 BC, 4 CA => 1 FUEL").unwrap(), "165");
         assert_eq!(super::part1("157 ORE => 5 NZVS
 165 ORE => 6 DCFZ
 44 XJWVT, 5 KHKGT, 1 QDVJ, 29 NZVS, 9 GPVTF, 48 HKGWZ => 1 FUEL
@@ -145,7 +173,7 @@ mod tests {
 177 ORE => 5 HKGWZ
 7 DCFZ, 7 PSHF => 2 XJWVT
 165 ORE => 2 GPVTF
-3 DCFZ, 7 NZVS, 5 HKGWZ, 10 PSHF => 8 KHKGT"), "13312");
+3 DCFZ, 7 NZVS, 5 HKGWZ, 10 PSHF => 8 KHKGT").unwrap(), "13312");
         assert_eq!(super::part1("2 VPVL, 7 FWMGM, 2 CXFTF, 11 MNCFX => 1 STKFG
 17 NVRVD, 3 JNWZP => 8 VPVL
 53 STKFG, 6 MNCFX, 46 VJHF, 81 HVMC, 68 CXFTF, 25 GNMV => 1 FUEL
@@ -157,7 +185,7 @@ mod tests {
 145 ORE => 6 MNCFX
 1 NVRVD => 8 CXFTF
 1 VJHF, 6 MNCFX => 4 RFSQX
-176 ORE => 6 VJHF"), "180697");
+176 ORE => 6 VJHF").unwrap(), "180697");
         assert_eq!(super::part1("171 ORE => 8 CNZTR
 7 ZLQW, 3 BMBT, 9 XCVML, 26 XMNCP, 1 WPTQ, 2 MZWV, 1 RJRHP => 4 PLWSL
 114 ORE => 4 BHXH
@@ -174,7 +202,7 @@ mod tests {
 3 BHXH, 2 VRPVC => 7 MZWV
 121 ORE => 7 VRPVC
 7 XCVML => 6 RJRHP
-5 BHXH, 4 VRPVC => 5 LTCX"), "2210736");
+5 BHXH, 4 VRPVC => 5 LTCX").unwrap(), "2210736");
     }
 
     #[test]
@@ -187,7 +215,7 @@ mod tests {
 177 ORE => 5 HKGWZ
 7 DCFZ, 7 PSHF => 2 XJWVT
 165 ORE => 2 GPVTF
-3 DCFZ, 7 NZVS, 5 HKGWZ, 10 PSHF => 8 KHKGT"), "82892753");
+3 DCFZ, 7 NZVS, 5 HKGWZ, 10 PSHF => 8 KHKGT", 1_000_000_000_000).unwrap(), "82892753");
         assert_eq!(super::part2("2 VPVL, 7 FWMGM, 2 CXFTF, 11 MNCFX => 1 STKFG
 17 NVRVD, 3 JNWZP => 8 VPVL
 53 STKFG, 6 MNCFX, 46 VJHF, 81 HVMC, 68 CXFTF, 25 GNMV => 1 FUEL
@@ -199,7 +227,7 @@ mod tests {
 145 ORE => 6 MNCFX
 1 NVRVD => 8 CXFTF
 1 VJHF, 6 MNCFX => 4 RFSQX
-176 ORE => 6 VJHF"), "5586022");
+176 ORE => 6 VJHF", 1_000_000_000_000).unwrap(), "5586022");
         assert_eq!(super::part2("171 ORE => 8 CNZTR
 7 ZLQW, 3 BMBT, 9 XCVML, 26 XMNCP, 1 WPTQ, 2 MZWV, 1 RJRHP => 4 PLWSL
 114 ORE => 4 BHXH
@@ -216,6 +244,6 @@ mod tests {
 3 BHXH, 2 VRPVC => 7 MZWV
 121 ORE => 7 VRPVC
 7 XCVML => 6 RJRHP
-5 BHXH, 4 VRPVC => 5 LTCX"), "460664");
+5 BHXH, 4 VRPVC => 5 LTCX", 1_000_000_000_000).unwrap(), "460664");
     }
 }
@@ -0,0 +1,130 @@
+use std::collections::HashMap;
+use num::Complex;
+use crate::intcode::{self,Machine};
+use crate::intcode::search::{Node,Search,Strategy};
+use crate::error::{Answer,SolveError};
+use super::{Params,Solution};
+
+// Helper
+type Position = Complex<i64>;
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Cell {
+    Empty,
+    Oxygen
+}
+type Droid = (Position, Cell);
+
+fn moves (node: &Node<Droid>) -> Result<Vec<Node<Droid>>, SolveError> {
+    let (position, _) = node.state;
+    let movements = [(1, Complex::new(0, -1)), (2, Complex::new(0, 1)), (3, Complex::new(-1, 0)), (4, Complex::new(1, 0))];
+    let mut nexts = Vec::new();
+    for (input, movement) in movements.iter() {
+        let (machine, outputs) = node.fork(&[*input]).map_err(SolveError::fault)?;
+        let cell = match outputs[..] {
+            [0] => continue,
+            [1] => Cell::Empty,
+            [2] => Cell::Oxygen,
+            _ => return Err(SolveError::intcode(format!("expected a status code, got {:?}", outputs)))
+        };
+        nexts.push(node.next(machine, (position + movement, cell), 1));
+    }
+    Ok(nexts)
+}
+fn find_oxygen (opcodes: &[i64]) -> Result<Node<Droid>, SolveError> {
+    let start = Node::new(Machine::new(&opcodes.to_vec()), (Complex::new(0, 0), Cell::Empty));
+    Search::new(|(position, _): &Droid| *position)
+        .run(Strategy::Bfs, start, moves, |node| node.state.1 == Cell::Oxygen)?
+        .ok_or(SolveError::unsolvable("no oxygen system found"))
+}
+
+// Part1
+pub fn part1 (input: &str) -> Result<Answer, SolveError> {
+    let opcodes = intcode::parse(input)?;
+    Ok(format!("{}", find_oxygen(&opcodes)?.cost))
+}
+
+// Part2
+pub fn part2 (input: &str) -> Result<Answer, SolveError> {
+    let opcodes = intcode::parse(input)?;
+    let oxygen = find_oxygen(&opcodes)?;
+    let mut max_distance = 0;
+    Search::new(|(position, _): &Droid| *position)
+        .run(Strategy::Bfs, Node { cost: 0, ..oxygen }, moves, |node| {
+            max_distance = max_distance.max(node.cost);
+            false
+        })?;
+    Ok(format!("{}", max_distance))
+}
+
+// Visual
+// Explored maze: `D` the start, `O` the oxygen system, `.` open cells and `#` the walls around them
+fn render (input: &str) -> Result<String, SolveError> {
+    let opcodes = intcode::parse(input)?;
+    let start = Node::new(Machine::new(&opcodes), (Complex::new(0, 0), Cell::Empty));
+    let mut cells = HashMap::new();
+    Search::new(|(position, _): &Droid| *position)
+        .run(Strategy::Bfs, start, moves, |node| {
+            cells.insert(node.state.0, node.state.1);
+            false
+        })?;
+
+    let movements = [Complex::new(0, -1), Complex::new(0, 1), Complex::new(-1, 0), Complex::new(1, 0)];
+    let (min_x, max_x) = (cells.keys().map(|p| p.re).min().unwrap_or(0) - 1, cells.keys().map(|p| p.re).max().unwrap_or(0) + 1);
+    let (min_y, max_y) = (cells.keys().map(|p| p.im).min().unwrap_or(0) - 1, cells.keys().map(|p| p.im).max().unwrap_or(0) + 1);
+    let mut out = String::new();
+    for y in min_y..=max_y {
+        for x in min_x..=max_x {
+            let position = Complex::new(x, y);
+            out.push(match cells.get(&position) {
+                _ if position == Complex::new(0, 0) => 'D',
+                Some(Cell::Oxygen) => 'O',
+                Some(Cell::Empty) => '.',
+                None if movements.iter().any(|movement| cells.contains_key(&(position + movement))) => '#',
+                None => ' '
+            });
+        }
+        out += "\n";
+    }
+    Ok(out)
+}
+
+// Solution
+pub struct Day;
+impl Solution for Day {
+    fn year (&self) -> u16 { 2019 }
+    fn day (&self) -> u8 { 15 }
+    fn title (&self) -> &'static str { "Oxygen System" }
+    fn part1 (&self, input: &str, _params: &Params) -> Result<Answer, SolveError> { part1(input) }
+    fn part2 (&self, input: &str, _params: &Params) -> Result<Answer, SolveError> { part2(input) }
+    fn visual (&self, input: &str, _params: &Params) -> Option<Result<String, SolveError>> { Some(render(input)) }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    // Droid walking this maze, `D` its start and `O` the oxygen system:
+    // #######
+    // #D.#..#
+    // #.##.##
+    // #...O.#
+    // ###.###
+    // #######
+    const DROID: &str = "3,58,1001,58,67,7,1001,0,0,59,1001,58,72,15,1001,0,0,60,1,65,59,61,1,66,60,62,1002,62,7,63,1,63,61,63,1001,63,77,39,1001,0,0,64,4,64,1006,64,0,1001,61,0,65,1001,62,0,66,1105,1,0,0,0,0,0,0,0,0,1,1,0,0,0,-1,1,0,-1,1,0,0,0,0,0,0,0,0,0,0,1,1,0,1,1,0,0,1,0,0,1,0,0,0,1,1,1,2,1,0,0,0,0,1,0,0,0,0,0,0,0,0,0,0";
+
+    #[test]
+    fn day15_part1 () {
+        assert_eq!(super::part1(DROID).unwrap(), "5");
+        assert!(super::part1("0").is_err());
+    }
+
+    #[test]
+    fn day15_part2 () {
+        assert_eq!(super::part2(DROID).unwrap(), "6");
+        assert!(super::part2("0").is_err());
+    }
+
+    #[test]
+    fn day15_render () {
+        assert_eq!(super::render(DROID).unwrap(), " ## ## \n#D.#..#\n#.##.# \n#...O.#\n ##.## \n   #   \n");
+    }
+}
@@ -0,0 +1,160 @@
+use itertools::Itertools;
+use ndarray::{Array1,Array2};
+use crate::error::{Answer,SolveError};
+use crate::runner::Part;
+use super::{Implementation,Params,Solution};
+
+// Helper
+fn read_input (input: &str) -> Result<Vec<i64>, SolveError> {
+    input
+        .trim()
+        .chars()
+        .enumerate()
+        .map(|(column, c)| match c {
+            '0' => Ok(0),
+            '1' => Ok(1),
+            '2' => Ok(2),
+            '3' => Ok(3),
+            '4' => Ok(4),
+            '5' => Ok(5),
+            '6' => Ok(6),
+            '7' => Ok(7),
+            '8' => Ok(8),
+            '9' => Ok(9),
+            err => Err(SolveError::parse(1, column + 1, format!("{:?} is not a digit", err)))
+        })
+        .collect()
+}
+fn create_matrix (n: usize) -> Array2<i64> {
+    let mut matrix = Array2::<i64>::zeros((n, n));
+    for y in 0..n {
+        let v: Vec<i64> = vec![
+            vec![0; y+1],
+            vec![1; y+1],
+            vec![0; y+1],
+            vec![-1; y+1]
+        ]
+            .iter()
+            .flat_map(|x| x)
+            .cycle()
+            .take(n+1)
+            .map(|x| x.clone())
+            .collect();
+        for x in 0..n {
+            *matrix
+                .get_mut((y, x))
+                .expect("To have value") = v[x + 1];
+        }
+    }
+    matrix
+}
+
+// Part1
+pub fn part1 (input: &str) -> Result<Answer, SolveError> {
+    let input = read_input(input)?;
+    let matrix = create_matrix(input.len());
+    let mut vec = Array1::from(input);
+    for _ in 0..100 {
+        vec = matrix.dot(&vec).map(|x| x.abs() % 10);
+    }
+
+    Ok(vec
+        .iter()
+        .take(8)
+        .fold(String::new(), |a, b| format!("{}{}", a, b)))
+}
+
+// Phases with prefix sums: each digit adds and subtracts runs of consecutive digits, O(n log n) instead of O(n²)
+pub fn part1_prefix_sum (input: &str) -> Result<Answer, SolveError> {
+    let mut signal = read_input(input)?;
+    let n = signal.len();
+    for _ in 0..100 {
+        let mut sums = vec![0; n + 1];
+        for (i, digit) in signal.iter().enumerate() {
+            sums[i + 1] = sums[i] + digit;
+        }
+        let range = |from: usize, to: usize| sums[to.min(n)] - sums[from.min(n)];
+        signal = (1..=n)
+            .map(|length| {
+                let mut total = 0;
+                // Runs of 1 start at length - 1, runs of -1 two lengths later, every 4 lengths
+                for start in (length - 1..n).step_by(4 * length) {
+                    total += range(start, start + length) - range(start + 2 * length, start + 3 * length);
+                }
+                total.abs() % 10
+            })
+            .collect();
+    }
+
+    Ok(signal
+        .iter()
+        .take(8)
+        .fold(String::new(), |a, b| format!("{}{}", a, b)))
+}
+
+// Part2
+pub fn part2 (input: &str) -> Result<Answer, SolveError> {
+    let mut input = read_input(input)?;
+    if input.len() < 7 {
+        return Err(SolveError::unsolvable("the signal is too short to hold a message offset"));
+    }
+    let index = (0..7)
+        .fold(0usize, |a, b| 10 * a + input[b] as usize);
+    input = input
+        .iter()
+        .cycle()
+        .take(10000 * input.len())
+        .skip(index)
+        .map(|x| x.clone())
+        .collect();
+    for _ in 0..100 {
+        let mut new_input = input.clone();
+        let mut count = 0;
+        for i in 1..=input.len() {
+            count = (count + input[input.len() - i]).abs() % 10;
+            new_input[input.len() - i] = count;
+        }
+
+        input = new_input;
+    }
+
+    Ok(input
+        .iter()
+        .take(8)
+        .fold(String::new(), |a, b| format!("{}{}", a, b)))
+}
+
+// Solution
+const ALTERNATIVES: &[Implementation] = &[
+    Implementation { name: "prefix-sum", part: Part::One, description: "Prefix sums instead of the dense matrix", run: |input, _| part1_prefix_sum(input) }
+];
+
+pub struct Day;
+impl Solution for Day {
+    fn year (&self) -> u16 { 2019 }
+    fn day (&self) -> u8 { 16 }
+    fn title (&self) -> &'static str { "Flawed Frequency Transmission" }
+    fn part1 (&self, input: &str, _params: &Params) -> Result<Answer, SolveError> { part1(input) }
+    fn part2 (&self, input: &str, _params: &Params) -> Result<Answer, SolveError> { part2(input) }
+    fn alternatives (&self) -> &'static [Implementation] { ALTERNATIVES }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn day16_part1 () {
+        assert_eq!(super::part1("80871224585914546619083218645595").unwrap(), String::from("24176176"));
+        assert_eq!(super::part1("19617804207202209144916044189917").unwrap(), String::from("73745418"));
+        assert_eq!(super::part1("69317163492948606335995924319873").unwrap(), String::from("52432133"));
+        assert_eq!(super::part1_prefix_sum("80871224585914546619083218645595").unwrap(), String::from("24176176"));
+        assert_eq!(super::part1_prefix_sum("69317163492948606335995924319873").unwrap(), String::from("52432133"));
+    }
+
+    #[test]
+    fn day16_part2 () {
+        assert_eq!(super::part2("03036732577212944063491565474664").unwrap(), "84462026");
+        assert_eq!(super::part2("02935109699940807407585447034323").unwrap(), "78725270");
+        assert_eq!(super::part2("03081770884921959731165446850517").unwrap(), "53553731");
+    }
+}
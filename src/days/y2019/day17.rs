@@ -1,18 +1,11 @@
 use std::collections::HashMap;
 use onig::{Regex,Captures};
 use num::Complex;
-use crate::intcode::{Opcodes,Status,Machine};
+use crate::intcode::{self,Status,Machine};
+use crate::error::{Answer,SolveError};
+use super::{Params,Solution};
 
 // Helper
-fn read_input (input: &str) -> Opcodes {
-    input
-        .lines()
-        .collect::<Vec<&str>>()
-        .join("")
-        .split(",")
-        .map(|s| s.trim().parse().expect("Cannot parse int"))
-        .collect()
-}
 struct Map {
     bot_pos: Complex<i64>,
     bot_orientation: Complex<i64>,
@@ -22,7 +15,7 @@ struct Map {
     machine: Machine
 }
 impl Map {
-    fn new (mut machine: Machine) -> Self {
+    fn new (mut machine: Machine) -> Result<Self, SolveError> {
         let mut map: HashMap<Complex<i64>, char> = HashMap::new();
         let mut pos = Complex::new(0, 0);
         let mut max_x = 0;
@@ -67,18 +60,18 @@ impl Map {
                     pos.re += 1;
                 },
                 Status::Ok => {},
-                err => { println!("{:?}", err) }
+                Status::Error(opcode) => return Err(SolveError::fault(opcode))
             }
         }
 
-        Self {
+        Ok(Self {
             map,
             max_y,
             max_x,
             bot_orientation,
             bot_pos,
             machine
-        }
+        })
     }
 
     #[allow(dead_code)]
@@ -137,13 +130,15 @@ impl Map {
             .fold(String::from(""), |a, b| a + b + ",")
     }
 
-    fn feed_routine(self: &mut Self, CompressedPath {a, b, c, routine}: CompressedPath) {
+    fn feed_routine(self: &mut Self, CompressedPath {a, b, c, routine}: CompressedPath) -> Result<(), SolveError> {
         for string in &[routine, a, b, c, String::from("n")] {
             loop {
                 match self.machine.run_until_interrupted() {
-                    Status::Output(o) => print!("{}", o as u8 as char),
+                    // Prompts, e.g. "Main:"
+                    Status::Output(_) => {},
                     Status::WaitingForInput => break,
-                    err => panic!("{:?}", err)
+                    Status::Error(opcode) => return Err(SolveError::fault(opcode)),
+                    status => return Err(SolveError::intcode(format!("unexpected state {:?} while feeding the routine", status)))
                 }
             }
 
@@ -152,6 +147,7 @@ impl Map {
             }
             self.machine.add_input_mut(10);
         }
+        Ok(())
     }
 }
 struct CompressedPath {
@@ -160,20 +156,21 @@ struct CompressedPath {
     c: String,
     routine: String
 }
-fn compress_path (path: &String) -> CompressedPath {
+fn compress_path (path: &String) -> Result<CompressedPath, SolveError> {
+    let unsolvable = || SolveError::unsolvable(format!("cannot split the path into three functions: {}", path));
     let caps = Regex::new(r"^(.{1,21})\1*(.{1,21})(?:\1|\2)*(.{1,21})(?:\1|\2|\3)*$")
         .unwrap()
         .captures(path)
-        .unwrap();
+        .ok_or_else(unsolvable)?;
 
     let out: Vec<String> = caps
         .iter()
         .map(|cap| {
-            let mut s = cap.unwrap().to_string();
+            let mut s = cap.ok_or_else(unsolvable)?.to_string();
             s.pop();
-            s
+            Ok(s)
         })
-        .collect();
+        .collect::<Result<Vec<String>, SolveError>>()?;
 
     let a = out[1].clone();
     let b = out[2].clone();
@@ -189,24 +186,24 @@ fn compress_path (path: &String) -> CompressedPath {
         .map(|c| format!("{}", c))
         .collect();
 
-    println!("A: {:?}", a);
-    println!("B: {:?}", b);
-    println!("C: {:?}", c);
-    println!("out: {:?}", routine);
+    // println!("A: {:?}", a);
+    // println!("B: {:?}", b);
+    // println!("C: {:?}", c);
+    // println!("out: {:?}", routine);
 
-    CompressedPath {
+    Ok(CompressedPath {
         a,
         b,
         c,
         routine
-    }
+    })
 }
 
 // Part1
-pub fn part1 (input: &str) -> String {
-    let opcodes = read_input(input);
+pub fn part1 (input: &str) -> Result<Answer, SolveError> {
+    let opcodes = intcode::parse(input)?;
 
-    let map = Map::new(Machine::new(&opcodes));
+    let map = Map::new(Machine::new(&opcodes))?;
 
     // map.display();
 
@@ -222,34 +219,50 @@ pub fn part1 (input: &str) -> String {
             out += x * y
         }
     }
-    format!("{}", out)
+    Ok(format!("{}", out))
 }
 
 // Part2
-pub fn part2 (input: &str) -> String {
-    let mut opcodes = read_input(input);
+pub fn part2 (input: &str) -> Result<Answer, SolveError> {
+    let mut opcodes = intcode::parse(input)?;
+    if opcodes.is_empty() {
+        return Err(SolveError::unsolvable("program too short to wake the robot up"));
+    }
     opcodes[0] = 2;
 
-    let mut map = Map::new(Machine::new(&opcodes));
+    let mut map = Map::new(Machine::new(&opcodes))?;
 
     // map.display();
 
     let instructions = map.get_instruction();
-    let routine = compress_path(&instructions);
+    let routine = compress_path(&instructions)?;
 
-    map.feed_routine(routine);
+    map.feed_routine(routine)?;
 
     let mut out = None;
     loop {
         match map.machine.run_until_interrupted() {
             Status::Output(o) => out = Some(o),
             Status::Halt => break,
-            err => panic!("{:?}", err)
+            Status::Error(opcode) => return Err(SolveError::fault(opcode)),
+            status => return Err(SolveError::intcode(format!("unexpected state {:?}", status)))
         }
     }
 
 
-    format!("{}", out.unwrap())
+    out
+        .map(|o| format!("{}", o))
+        .ok_or(SolveError::unsolvable("the robot halted without reporting the dust"))
+}
+
+// Solution
+pub struct Day;
+impl Solution for Day {
+    fn year (&self) -> u16 { 2019 }
+    fn day (&self) -> u8 { 17 }
+    fn title (&self) -> &'static str { "Set and Forget" }
+    fn part1 (&self, input: &str, _params: &Params) -> Result<Answer, SolveError> { part1(input) }
+    fn part2 (&self, input: &str, _params: &Params) -> Result<Answer, SolveError> { part2(input) }
 }
 
 // Tests
@@ -257,11 +270,13 @@ pub fn part2 (input: &str) -> String {
 mod tests {
     #[test]
     fn day17_part1 () {
-        assert_eq!(super::part1("104,46,104,46,104,35,104,46,104,46,104,46,104,46,104,46,104,46,104,46,104,46,104,46,104,46,104,10,104,46,104,46,104,35,104,46,104,46,104,46,104,46,104,46,104,46,104,46,104,46,104,46,104,46,104,10,104,35,104,35,104,35,104,35,104,35,104,35,104,35,104,46,104,46,104,46,104,35,104,35,104,35,104,10,104,35,104,46,104,35,104,46,104,46,104,46,104,35,104,46,104,46,104,46,104,35,104,46,104,35,104,10,104,35,104,35,104,35,104,35,104,35,104,35,104,35,104,35,104,35,104,35,104,35,104,35,104,35,104,10,104,46,104,46,104,35,104,46,104,46,104,46,104,35,104,46,104,46,104,46,104,35,104,46,104,46,104,10,104,46,104,46,104,35,104,35,104,35,104,35,104,35,104,46,104,46,104,46,104,35,104,46,104,46,104,10,99"), "76");
+        assert_eq!(super::part1("104,46,104,46,104,35,104,46,104,46,104,46,104,46,104,46,104,46,104,46,104,46,104,46,104,46,104,10,104,46,104,46,104,35,104,46,104,46,104,46,104,46,104,46,104,46,104,46,104,46,104,46,104,46,104,10,104,35,104,35,104,35,104,35,104,35,104,35,104,35,104,46,104,46,104,46,104,35,104,35,104,35,104,10,104,35,104,46,104,35,104,46,104,46,104,46,104,35,104,46,104,46,104,46,104,35,104,46,104,35,104,10,104,35,104,35,104,35,104,35,104,35,104,35,104,35,104,35,104,35,104,35,104,35,104,35,104,35,104,10,104,46,104,46,104,35,104,46,104,46,104,46,104,35,104,46,104,46,104,46,104,35,104,46,104,46,104,10,104,46,104,46,104,35,104,35,104,35,104,35,104,35,104,46,104,46,104,46,104,35,104,46,104,46,104,10,99").unwrap(), "76");
     }
 
     #[test]
     fn day17_part2 () {
-        assert_eq!(super::part2("104,35,104,35,104,35,104,35,104,35,104,35,104,35,104,46,104,46,104,46,104,35,104,35,104,35,104,35,104,35,104,10,104,35,104,46,104,46,104,46,104,46,104,46,104,35,104,46,104,46,104,46,104,35,104,46,104,46,104,46,104,35,104,10,104,35,104,46,104,46,104,46,104,46,104,46,104,35,104,46,104,46,104,46,104,35,104,46,104,46,104,46,104,35,104,10,104,46,104,46,104,46,104,46,104,46,104,46,104,35,104,46,104,46,104,46,104,35,104,46,104,46,104,46,104,35,104,10,104,46,104,46,104,46,104,46,104,46,104,46,104,35,104,46,104,46,104,46,104,35,104,35,104,35,104,46,104,35,104,10,104,46,104,46,104,46,104,46,104,46,104,46,104,35,104,46,104,46,104,46,104,46,104,46,104,35,104,46,104,35,104,10,104,94,104,35,104,35,104,35,104,35,104,35,104,35,104,35,104,35,104,46,104,46,104,46,104,35,104,46,104,35,104,10,104,46,104,46,104,46,104,46,104,46,104,46,104,35,104,46,104,35,104,46,104,46,104,46,104,35,104,46,104,35,104,10,104,46,104,46,104,46,104,46,104,46,104,46,104,35,104,35,104,35,104,35,104,35,104,35,104,35,104,35,104,35,104,10,104,46,104,46,104,46,104,46,104,46,104,46,104,46,104,46,104,35,104,46,104,46,104,46,104,35,104,46,104,46,104,10,104,46,104,46,104,46,104,46,104,35,104,35,104,35,104,35,104,35,104,35,104,35,104,35,104,35,104,46,104,46,104,10,104,46,104,46,104,46,104,46,104,35,104,46,104,46,104,46,104,35,104,46,104,46,104,46,104,46,104,46,104,46,104,10,104,46,104,46,104,46,104,46,104,35,104,46,104,46,104,46,104,35,104,46,104,46,104,46,104,46,104,46,104,46,104,10,104,46,104,46,104,46,104,46,104,35,104,46,104,46,104,46,104,35,104,46,104,46,104,46,104,46,104,46,104,46,104,10,104,46,104,46,104,46,104,46,104,35,104,35,104,35,104,35,104,35,104,46,104,46,104,46,104,46,104,46,104,46,99"), "");
+        // The example scaffold, then a robot reporting 42 once it has read the routine, the three functions and the video flag
+        assert_eq!(super::part2("1,0,0,3,104,35,104,35,104,35,104,35,104,35,104,35,104,35,104,46,104,46,104,46,104,35,104,35,104,35,104,35,104,35,104,10,104,35,104,46,104,46,104,46,104,46,104,46,104,35,104,46,104,46,104,46,104,35,104,46,104,46,104,46,104,35,104,10,104,35,104,46,104,46,104,46,104,46,104,46,104,35,104,46,104,46,104,46,104,35,104,46,104,46,104,46,104,35,104,10,104,46,104,46,104,46,104,46,104,46,104,46,104,35,104,46,104,46,104,46,104,35,104,46,104,46,104,46,104,35,104,10,104,46,104,46,104,46,104,46,104,46,104,46,104,35,104,46,104,46,104,46,104,35,104,35,104,35,104,46,104,35,104,10,104,46,104,46,104,46,104,46,104,46,104,46,104,35,104,46,104,46,104,46,104,46,104,46,104,35,104,46,104,35,104,10,104,94,104,35,104,35,104,35,104,35,104,35,104,35,104,35,104,35,104,46,104,46,104,46,104,35,104,46,104,35,104,10,104,46,104,46,104,46,104,46,104,46,104,46,104,35,104,46,104,35,104,46,104,46,104,46,104,35,104,46,104,35,104,10,104,46,104,46,104,46,104,46,104,46,104,46,104,35,104,35,104,35,104,35,104,35,104,35,104,35,104,35,104,35,104,10,104,46,104,46,104,46,104,46,104,46,104,46,104,46,104,46,104,35,104,46,104,46,104,46,104,35,104,46,104,46,104,10,104,46,104,46,104,46,104,46,104,35,104,35,104,35,104,35,104,35,104,35,104,35,104,35,104,35,104,46,104,46,104,10,104,46,104,46,104,46,104,46,104,35,104,46,104,46,104,46,104,35,104,46,104,46,104,46,104,46,104,46,104,46,104,10,104,46,104,46,104,46,104,46,104,35,104,46,104,46,104,46,104,35,104,46,104,46,104,46,104,46,104,46,104,46,104,10,104,46,104,46,104,46,104,46,104,35,104,46,104,46,104,46,104,35,104,46,104,46,104,46,104,46,104,46,104,46,104,10,104,46,104,46,104,46,104,46,104,35,104,35,104,35,104,35,104,35,104,46,104,46,104,46,104,46,104,46,104,46,3,501,1008,501,10,502,1006,502,482,1001,503,-1,503,1005,503,482,104,42,99,0,0,5").unwrap(), "42");
+        assert!(super::part2("").is_err());
     }
 }
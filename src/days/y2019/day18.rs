@@ -1,6 +1,8 @@
 use num::Complex;
 use std::collections::{HashMap,HashSet,BinaryHeap,VecDeque};
 use std::cmp::Ordering;
+use crate::error::{Answer,SolveError};
+use super::{Params,Solution};
 
 // Helper
 #[derive(Debug)]
@@ -20,7 +22,7 @@ struct Maze {
     keys_count: usize
 }
 impl Maze {
-    fn new (input: &str) -> Self {
+    fn new (input: &str) -> Result<Self, SolveError> {
         let mut map = HashMap::new();
         let mut pos = None;
         let mut keys_count = 0;
@@ -38,20 +40,21 @@ impl Maze {
                     '#' => Cell::Wall,
                     '.' | '@' => Cell::Hall,
                     'a'..='z' => Cell::Key(c),
-                    'A'..='Z' => Cell::Door(c.to_lowercase().nth(0).unwrap()),
-                    _ => panic!("{} is not a known char", c)
+                    'A'..='Z' => Cell::Door(c.to_ascii_lowercase()),
+                    _ => return Err(SolveError::parse(y + 1, x + 1, format!("{:?} is not a known char", c)))
                 });
             }
         }
-        Self {
+        Ok(Self {
             map,
-            pos: pos.unwrap(),
+            pos: pos.ok_or(SolveError::unsolvable("no entrance `@` in the maze"))?,
             height: input.lines().count(),
-            width: input.lines().nth(0).unwrap().len(),
+            width: input.lines().nth(0).map_or(0, |line| line.len()),
             keys_count
-        }
+        })
     }
 
+    #[allow(dead_code)]
     fn display (self: &Self) {
         for y in 0..self.height {
             for x in 0..self.width {
@@ -80,7 +83,11 @@ impl Maze {
             visited.insert(state.pos);
             for direction in &[Complex::new(1, 0), Complex::new(-1, 0), Complex::new(0, 1), Complex::new(0, -1)] {
                 let pos = state.pos + direction;
-                let cell = self.map.get(&pos).unwrap();
+                // Outside of the map
+                let cell = match self.map.get(&pos) {
+                    Some(cell) => cell,
+                    None => continue
+                };
 
                 if visited.contains(&pos) { continue }
 
@@ -142,9 +149,9 @@ impl PartialEq for State {
 }
 
 // Part1
-pub fn part1 (input: &str) -> String {
-    let maze = Maze::new(input);
-    maze.display();
+pub fn part1 (input: &str) -> Result<Answer, SolveError> {
+    let maze = Maze::new(input)?;
+    // maze.display();
 
     let init_state = State {
         distance: 0,
@@ -156,20 +163,31 @@ pub fn part1 (input: &str) -> String {
 
     while let Some(state) = q.pop() {
         if state.collected_keys.len() == maze.keys_count {
-            return format!("{}", state.distance)
+            return Ok(format!("{}", state.distance))
         }
         for next_state in maze.next_states(state) {
             q.push(next_state)
         }
     }
 
-    format!("{}", 0)
+    Err(SolveError::unsolvable("some keys cannot be reached"))
 }
 
 // Part2
-pub fn part2 (input: &str) -> String {
-    let maze = Maze::new(input);
-    format!("{}", 0)
+pub fn part2 (input: &str) -> Result<Answer, SolveError> {
+    Maze::new(input)?;
+    Ok(format!("{}", 0))
+}
+
+// Solution
+pub struct Day;
+impl Solution for Day {
+    fn year (&self) -> u16 { 2019 }
+    fn day (&self) -> u8 { 18 }
+    fn title (&self) -> &'static str { "Many-Worlds Interpretation" }
+    fn part1 (&self, input: &str, _params: &Params) -> Result<Answer, SolveError> { part1(input) }
+    fn part2 (&self, input: &str, _params: &Params) -> Result<Answer, SolveError> { part2(input) }
+    fn implemented (&self) -> (bool, bool) { (true, false) }
 }
 
 // Tests
@@ -177,15 +195,10 @@ pub fn part2 (input: &str) -> String {
 mod tests {
     #[test]
     fn day18_part1 () {
-        assert_eq!(super::part1("#########\n#b.A.@.a#\n#########"), "8");
-        assert_eq!(super::part1("########################\n#f.D.E.e.C.b.A.@.a.B.c.#\n######################.#\n#d.....................#\n########################"), "86");
-        assert_eq!(super::part1("########################\n#...............b.C.D.f#\n#.######################\n#.....@.a.B.c.d.A.e.F.g#\n########################"), "132");
-        // assert_eq!(super::part1("#################\n#i.G..c...e..H.p#\n########.########\n#j.A..b...f..D.o#\n########@########\n#k.E..a...g..B.n#\n########.########\n#l.F..d...h..C.m#\n#################"), "136");
-        assert_eq!(super::part1("########################\n#@..............ac.GI.b#\n###d#e#f################\n###A#B#C################\n###g#h#i################\n########################"), "81");
-    }
-
-    #[test]
-    fn day18_part2 () {
-        assert_eq!(super::part2("0"), "0");
+        assert_eq!(super::part1("#########\n#b.A.@.a#\n#########").unwrap(), "8");
+        assert_eq!(super::part1("########################\n#f.D.E.e.C.b.A.@.a.B.c.#\n######################.#\n#d.....................#\n########################").unwrap(), "86");
+        assert_eq!(super::part1("########################\n#...............b.C.D.f#\n#.######################\n#.....@.a.B.c.d.A.e.F.g#\n########################").unwrap(), "132");
+        // assert_eq!(super::part1("#################\n#i.G..c...e..H.p#\n########.########\n#j.A..b...f..D.o#\n########@########\n#k.E..a...g..B.n#\n########.########\n#l.F..d...h..C.m#\n#################").unwrap(), "136");
+        assert_eq!(super::part1("########################\n#@..............ac.GI.b#\n###d#e#f################\n###A#B#C################\n###g#h#i################\n########################").unwrap(), "81");
     }
 }
@@ -0,0 +1,89 @@
+use crate::intcode::{self,Machine,Status};
+use crate::error::{Answer,SolveError};
+use super::{Param,Params,Solution};
+
+// Helper
+// Noun and verb go to addresses 1 and 2
+fn check_length (opcodes: &[i64]) -> Result<(), SolveError> {
+    if opcodes.len() < 3 {
+        return Err(SolveError::unsolvable(format!("program too short for a noun and a verb ({} opcodes)", opcodes.len())));
+    }
+    Ok(())
+}
+
+// Part1
+pub fn part1 (input: &str) -> Result<Answer, SolveError> {
+    let mut opcodes = intcode::parse(input)?;
+    check_length(&opcodes)?;
+    opcodes[1] = 12;
+    opcodes[2] = 2;
+    let mut machine = Machine::new(&opcodes);
+    loop {
+        match machine.step() {
+            Status::Halt => break,
+            Status::Error(opcode) => return Err(SolveError::fault(opcode)),
+            _ => {}
+        }
+    }
+    let output = machine.opcodes[0];
+    Ok(format!("{}", output))
+}
+
+// Part2
+pub fn part2 (input: &str, expected_output: i64) -> Result<Answer, SolveError> {
+    let opcodes = intcode::parse(input)?;
+    check_length(&opcodes)?;
+    for noun in 0..100 {
+        for verb in 0..100 {
+            let mut opcodes = opcodes.clone();
+            opcodes[1] = noun;
+            opcodes[2] = verb;
+            let mut machine = Machine::new(&opcodes);
+            loop {
+                match machine.step() {
+                    Status::Halt => break,
+                    Status::Error(opcode) => return Err(SolveError::fault(opcode)),
+                    _ => {}
+                }
+            }
+            let output = machine.opcodes[0];
+            if expected_output == output {
+                return Ok(format!("noun = {}, verb = {}", noun, verb));
+            }
+        }
+    }
+    Err(SolveError::unsolvable(format!("no noun and verb give {}", expected_output)))
+}
+
+// Solution
+const PARAMS: &[Param] = &[
+    Param { name: "target", default: 19690720, description: "Output the noun and verb have to produce" }
+];
+
+pub struct Day;
+impl Solution for Day {
+    fn year (&self) -> u16 { 2019 }
+    fn day (&self) -> u8 { 2 }
+    fn title (&self) -> &'static str { "1202 Program Alarm" }
+    fn part1 (&self, input: &str, _params: &Params) -> Result<Answer, SolveError> { part1(input) }
+    fn part2 (&self, input: &str, params: &Params) -> Result<Answer, SolveError> { part2(input, params.get("target")?) }
+    fn params (&self) -> &'static [Param] { PARAMS }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn day2_part1 () {
+        // assert_eq!(super::main_loop(super::read_input("1,0,0,0,99"), 0, 0), 2);
+        assert!(super::part1("99").is_err());
+    }
+
+    #[test]
+    fn day2_part2 () {
+        // Output = opcodes[noun] + opcodes[verb]
+        assert_eq!(super::part2("1,1,2,0,99", 30).unwrap(), "noun = 2, verb = 30");
+        assert!(super::part2("1,1,2,0,99", 500).is_err());
+        assert!(super::part2("99", 0).is_err());
+    }
+}
@@ -0,0 +1,44 @@
+use crate::error::{Answer,SolveError};
+use super::{Params,Solution};
+
+// Helper
+fn read_input (_input: &str) -> i32 {
+    0
+}
+
+// Part1
+pub fn part1 (input: &str) -> Result<Answer, SolveError> {
+    Ok(format!("{}", read_input(input)))
+}
+
+// Part2
+pub fn part2 (input: &str) -> Result<Answer, SolveError> {
+    Ok(format!("{}", read_input(input)))
+}
+
+// Solution
+pub struct Day;
+impl Solution for Day {
+    fn year (&self) -> u16 { 2019 }
+    fn day (&self) -> u8 { 20 }
+    fn title (&self) -> &'static str { "Donut Maze" }
+    fn part1 (&self, input: &str, _params: &Params) -> Result<Answer, SolveError> { part1(input) }
+    fn part2 (&self, input: &str, _params: &Params) -> Result<Answer, SolveError> { part2(input) }
+    // The maze is padded with spaces, and its rows have to keep their length
+    fn normalized_input (&self) -> bool { false }
+    fn implemented (&self) -> (bool, bool) { (false, false) }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn day20_part1 () {
+        assert_eq!(super::part1("0").unwrap(), "0");
+    }
+
+    #[test]
+    fn day20_part2 () {
+        assert_eq!(super::part2("0").unwrap(), "0");
+    }
+}
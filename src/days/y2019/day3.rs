@@ -0,0 +1,135 @@
+use num::Complex;
+use std::collections::{HashMap,HashSet};
+use crate::error::{self,Answer,SolveError};
+use super::{Params,Solution};
+
+// Helper
+struct Instruction {
+    dir: Complex<i32>,
+    length: usize,
+}
+impl Instruction {
+    pub fn new(s: &str, line: usize, column: usize) -> Result<Self, SolveError> {
+        let dir = match s.get(0..1) {
+            Some("R") => Complex::new(1, 0),
+            Some("L") => Complex::new(-1, 0),
+            Some("U") => Complex::new(0, -1),
+            Some("D") => Complex::new(0, 1),
+            _ => return Err(SolveError::parse(line, column, format!("invalid direction in {:?}", s)))
+        };
+        let length = error::number(&s[1..], line, column + 1)?;
+        Ok(Self {
+            dir,
+            length
+        })
+    }
+}
+fn read_input (input: &str) -> Result<Vec<Vec<Instruction>>, SolveError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let mut column = 1;
+            line
+                .split(",")
+                .map(|instr: &str| {
+                    let instruction = Instruction::new(instr, index + 1, column);
+                    column += instr.len() + 1;
+                    instruction
+                })
+                .collect()
+        })
+        .collect()
+}
+fn draw_lines (lines: Vec<Vec<Instruction>>) -> Vec<HashMap<Complex<i32>, i32>> {
+    lines
+        .iter()
+        .map(|line| {
+            let mut visited: HashMap<Complex<i32>, i32> = HashMap::new();
+            let mut pos: Complex<i32> = Complex::new(0, 0);
+            let mut step = 0;
+            for Instruction {dir, length} in line {
+                for _ in 1..=*length {
+                    step += 1;
+                    pos += dir;
+                    if visited.get(&pos).is_none() {
+                        visited.insert(pos, step);
+                    }
+                }
+            }
+            visited
+        })
+        .collect()
+}
+
+// Part1
+pub fn part1 (input: &str) -> Result<Answer, SolveError> {
+    let lines = read_input(input)?;
+    let all_positions = draw_lines(lines);
+    let (line1, line2) = match &all_positions[..] {
+        [line1, line2] => (line1, line2),
+        _ => return Err(SolveError::unsolvable("expected two wires"))
+    };
+    let positions1: HashSet<Complex<i32>> = line1.keys().map(|x| *x).collect();
+    let positions2: HashSet<Complex<i32>> = line2.keys().map(|x| *x).collect();
+    let min = positions1
+        .intersection(&positions2)
+        .map(|pos| pos.im.abs() + pos.re.abs())
+        .fold(100000, |a, b| a.min(b));
+    Ok(format!("{}", min))
+}
+
+// Part2
+pub fn part2 (input: &str) -> Result<Answer, SolveError> {
+    let lines = read_input(input)?;
+    let all_positions = draw_lines(lines);
+    let (line1, line2) = match &all_positions[..] {
+        [line1, line2] => (line1, line2),
+        _ => return Err(SolveError::unsolvable("expected two wires"))
+    };
+    let positions1: HashSet<Complex<i32>> = line1.keys().map(|x| *x).collect();
+    let positions2: HashSet<Complex<i32>> = line2.keys().map(|x| *x).collect();
+    let min = positions1
+        .intersection(&positions2)
+        .map(|pos| line1.get(pos).unwrap() + line2.get(pos).unwrap())
+        .fold(100000, |a, b| a.min(b));
+    Ok(format!("{}", min))
+}
+
+// Solution
+pub struct Day;
+impl Solution for Day {
+    fn year (&self) -> u16 { 2019 }
+    fn day (&self) -> u8 { 3 }
+    fn title (&self) -> &'static str { "Crossed Wires" }
+    fn part1 (&self, input: &str, _params: &Params) -> Result<Answer, SolveError> { part1(input) }
+    fn part2 (&self, input: &str, _params: &Params) -> Result<Answer, SolveError> { part2(input) }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use crate::error::SolveError;
+
+    #[test]
+    fn day3_part1 () {
+        assert_eq!(super::part1("R8,U5,L5,D3
+U7,R6,D4,L4").unwrap(), "6");
+        assert_eq!(super::part1("R75,D30,R83,U83,L12,D49,R71,U7,L72
+U62,R66,U55,R34,D71,R55,D58,R83").unwrap(), "159");
+        assert_eq!(super::part1("R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51
+U98,R91,D20,R16,D67,R40,U7,R15,U6,R7").unwrap(), "135");
+        assert_eq!(super::part1("R8,X5\nU7").unwrap_err(), SolveError::parse(1, 4, "invalid direction in \"X5\""));
+        assert_eq!(super::part1("R8,U5").unwrap_err(), SolveError::unsolvable("expected two wires"));
+    }
+
+    #[test]
+    fn day3_part2 () {
+        assert_eq!(super::part2("R8,U5,L5,D3
+U7,R6,D4,L4").unwrap(), "30");
+        assert_eq!(super::part2("R75,D30,R83,U83,L12,D49,R71,U7,L72
+U62,R66,U55,R34,D71,R55,D58,R83").unwrap(), "610");
+        assert_eq!(super::part2("R98,U47,R26,D63,R33,U87,L62,D20,R33,U53,R51
+U98,R91,D20,R16,D67,R40,U7,R15,U6,R7").unwrap(), "410");
+    }
+}
@@ -1,3 +1,6 @@
+use crate::error::{self,Answer,SolveError};
+use super::{Params,Solution};
+
 // Helper
 struct Counter {
     current: usize,
@@ -39,12 +42,12 @@ impl Iterator for Counter {
     }
 }
 
-fn read_input (input: &str) -> (usize, usize) {
-    let s: Vec<&str> = input.trim().split("-").collect();
-    (
-        s.get(0).unwrap().parse().unwrap(),
-        s.get(1).unwrap().parse().unwrap()
-    )
+fn read_input (input: &str) -> Result<(usize, usize), SolveError> {
+    let input = input.trim();
+    match input.split_once("-") {
+        Some((from, to)) => Ok((error::number(from, 1, 1)?, error::number(to, 1, from.len() + 2)?)),
+        None => Err(SolveError::parse(1, 1, format!("expected a range `from-to`, found {:?}", input)))
+    }
 }
 fn is_valid(mut n: usize) -> bool {
     let mut two_same = false;
@@ -84,21 +87,31 @@ fn is_valid_part2(mut n: usize) -> bool {
 }
 
 // Part1
-pub fn part1 (input: &str) -> String {
-    let (from, to) = read_input(input);
+pub fn part1 (input: &str) -> Result<Answer, SolveError> {
+    let (from, to) = read_input(input)?;
     let n = Counter::new(from, to)
         .filter(|i| is_valid(*i))
         .count();
-    return format!("{}", n)
+    Ok(format!("{}", n))
 }
 
 // Part2
-pub fn part2 (input: &str) -> String {
-    let (from, to) = read_input(input);
+pub fn part2 (input: &str) -> Result<Answer, SolveError> {
+    let (from, to) = read_input(input)?;
     let n = Counter::new(from, to)
         .filter(|i| is_valid_part2(*i))
         .count();
-    return format!("{}", n)
+    Ok(format!("{}", n))
+}
+
+// Solution
+pub struct Day;
+impl Solution for Day {
+    fn year (&self) -> u16 { 2019 }
+    fn day (&self) -> u8 { 4 }
+    fn title (&self) -> &'static str { "Secure Container" }
+    fn part1 (&self, input: &str, _params: &Params) -> Result<Answer, SolveError> { part1(input) }
+    fn part2 (&self, input: &str, _params: &Params) -> Result<Answer, SolveError> { part2(input) }
 }
 
 // Tests
@@ -106,15 +119,15 @@ pub fn part2 (input: &str) -> String {
 mod tests {
     #[test]
     fn day4_part1 () {
-        assert_eq!(super::part1("111111-111111"), "1");
-        assert_eq!(super::part1("223450-223450"), "0");
-        assert_eq!(super::part1("123789-123789"), "0");
+        assert_eq!(super::part1("111111-111111").unwrap(), "1");
+        assert_eq!(super::part1("223450-223450").unwrap(), "0");
+        assert_eq!(super::part1("123789-123789").unwrap(), "0");
     }
 
     #[test]
     fn day4_part2 () {
-        assert_eq!(super::part2("112233-112233"), "1");
-        assert_eq!(super::part2("123444-123444"), "0");
-        assert_eq!(super::part2("111122-111122"), "1");
+        assert_eq!(super::part2("112233-112233").unwrap(), "1");
+        assert_eq!(super::part2("123444-123444").unwrap(), "0");
+        assert_eq!(super::part2("111122-111122").unwrap(), "1");
     }
 }
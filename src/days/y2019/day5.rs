@@ -0,0 +1,59 @@
+use crate::intcode::{self,Status,Machine};
+use crate::error::{Answer,SolveError};
+use super::{Params,Solution};
+
+// Part1
+pub fn part1 (input: &str) -> Result<Answer, SolveError> {
+    let opcodes = intcode::parse(input)?;
+    let mut machine = Machine::new(&opcodes).add_input(1);
+    let mut out = None;
+    loop {
+        match machine.step() {
+            Status::Output(o) => out = Some(o),
+            Status::Halt => break,
+            Status::WaitingForInput => return Err(SolveError::unsolvable("the program asked for more input")),
+            Status::Error(opcode) => return Err(SolveError::fault(opcode)),
+            _ => {}
+        }
+    }
+    out
+        .map(|o| format!("{}", o))
+        .ok_or(SolveError::unsolvable("the program halted without output"))
+}
+
+// Part2
+pub fn part2 (input: &str) -> Result<Answer, SolveError> {
+    let opcodes = intcode::parse(input)?;
+    let mut machine = Machine::new(&opcodes).add_input(5);
+    loop {
+        match machine.step() {
+            Status::Output(o) => return Ok(format!("{}", o)),
+            Status::Halt => return Err(SolveError::unsolvable("the program halted without output")),
+            Status::WaitingForInput => return Err(SolveError::unsolvable("the program asked for more input")),
+            Status::Error(opcode) => return Err(SolveError::fault(opcode)),
+            _ => {}
+        }
+    }
+}
+
+// Solution
+pub struct Day;
+impl Solution for Day {
+    fn year (&self) -> u16 { 2019 }
+    fn day (&self) -> u8 { 5 }
+    fn title (&self) -> &'static str { "Sunny with a Chance of Asteroids" }
+    fn part1 (&self, input: &str, _params: &Params) -> Result<Answer, SolveError> { part1(input) }
+    fn part2 (&self, input: &str, _params: &Params) -> Result<Answer, SolveError> { part2(input) }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn day2_part1 () {
+    }
+
+    #[test]
+    fn day2_part2 () {
+    }
+}
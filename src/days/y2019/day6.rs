@@ -1,19 +1,24 @@
 use std::collections::{HashMap,HashSet,VecDeque};
+use crate::error::{Answer,SolveError};
+use super::{Params,Solution};
 
 // Helper
 type Graph = HashMap<String, HashSet<String>>;
-fn read_input (input: &str) -> Graph {
+fn read_input (input: &str) -> Result<Graph, SolveError> {
     let mut graph = HashMap::new();
-    for line in input.lines() {
-        let v: Vec<&str> = line.split(")").collect();
-        let center = String::from(v[0]);
-        let satellite = String::from(v[1]);
+    for (index, line) in input.lines().enumerate() {
+        let (center, satellite) = line
+            .trim()
+            .split_once(")")
+            .ok_or(SolveError::parse(index + 1, 1, format!("expected `CENTER)SATELLITE`, found {:?}", line)))?;
+        let center = String::from(center);
+        let satellite = String::from(satellite);
         graph
             .entry(center)
             .or_insert(HashSet::new())
             .insert(satellite);
     }
-    graph
+    Ok(graph)
 }
 fn get_n_satellites_and_n_orbits (graph: &Graph, node: &String) -> (usize, usize) {
     let mut n_satellites = 1;
@@ -45,23 +50,34 @@ fn path_to (graph: &Graph, root: &String, node: &String) -> Option<VecDeque<Stri
 }
 
 // Part1
-pub fn part1 (input: &str) -> String {
-    let graph = read_input(input);
+pub fn part1 (input: &str) -> Result<Answer, SolveError> {
+    let graph = read_input(input)?;
     let (_, n_orbits) = get_n_satellites_and_n_orbits(&graph, &String::from("COM"));
-    format!("{}", n_orbits)
+    Ok(format!("{}", n_orbits))
 }
 
 // Part2
-pub fn part2 (input: &str) -> String {
-    let graph = read_input(input);
-    let mut path_to_santa = path_to(&graph, &String::from("COM"), &String::from("SAN")).unwrap();
-    let mut path_to_you   = path_to(&graph, &String::from("COM"), &String::from("YOU")).unwrap();
+pub fn part2 (input: &str) -> Result<Answer, SolveError> {
+    let graph = read_input(input)?;
+    let unreachable = |node| SolveError::unsolvable(format!("{} does not orbit COM", node));
+    let mut path_to_santa = path_to(&graph, &String::from("COM"), &String::from("SAN")).ok_or(unreachable("SAN"))?;
+    let mut path_to_you   = path_to(&graph, &String::from("COM"), &String::from("YOU")).ok_or(unreachable("YOU"))?;
 
     while path_to_you.front() == path_to_santa.front() {
         path_to_santa.pop_front();
         path_to_you.pop_front();
     }
-    format!("{}", path_to_you.len() + path_to_santa.len())
+    Ok(format!("{}", path_to_you.len() + path_to_santa.len()))
+}
+
+// Solution
+pub struct Day;
+impl Solution for Day {
+    fn year (&self) -> u16 { 2019 }
+    fn day (&self) -> u8 { 6 }
+    fn title (&self) -> &'static str { "Universal Orbit Map" }
+    fn part1 (&self, input: &str, _params: &Params) -> Result<Answer, SolveError> { part1(input) }
+    fn part2 (&self, input: &str, _params: &Params) -> Result<Answer, SolveError> { part2(input) }
 }
 
 // Tests
@@ -69,11 +85,11 @@ pub fn part2 (input: &str) -> String {
 mod tests {
     #[test]
     fn day6_part1 () {
-        assert_eq!(super::part1("COM)B\nB)C\nC)D\nD)E\nE)F\nB)G\nG)H\nD)I\nE)J\nJ)K\nK)L"), "42");
+        assert_eq!(super::part1("COM)B\nB)C\nC)D\nD)E\nE)F\nB)G\nG)H\nD)I\nE)J\nJ)K\nK)L").unwrap(), "42");
     }
 
     #[test]
     fn day6_part2 () {
-        assert_eq!(super::part2("COM)B\nB)C\nC)D\nD)E\nE)F\nB)G\nG)H\nD)I\nE)J\nJ)K\nK)L\nK)YOU\nI)SAN"), "4");
+        assert_eq!(super::part2("COM)B\nB)C\nC)D\nD)E\nE)F\nB)G\nG)H\nD)I\nE)J\nJ)K\nK)L\nK)YOU\nI)SAN").unwrap(), "4");
     }
 }
@@ -0,0 +1,118 @@
+use itertools::Itertools;
+use crate::error::{Answer,SolveError};
+use crate::ocr;
+use super::{Param,Params,Solution};
+
+// Helper
+// Digits of the image, without the line ending or BOM an editor may have added around them
+fn digits (input: &str) -> &str {
+    input.trim_matches(|c: char| c.is_whitespace() || c == '\u{feff}')
+}
+
+// Part1
+pub fn part1 (input: &str, width: usize, height: usize) -> Result<Answer, SolveError> {
+    let nb_pixel = width * height;
+    if nb_pixel == 0 {
+        return Err(SolveError::unsolvable("empty image size"));
+    }
+
+    let mut min_0 = i64::max_value();
+    let mut output = 0;
+
+    for layer in digits(input).chars().chunks(nb_pixel).into_iter() {
+        let mut count_0 = 0;
+        let mut count_1 = 0;
+        let mut count_2 = 0;
+        for c in layer {
+            match c {
+                '0' => count_0 += 1,
+                '1' => count_1 += 1,
+                '2' => count_2 += 1,
+                _ => {}
+            }
+        }
+        if count_0 < min_0 {
+            output = count_1 * count_2;
+            min_0 = count_0;
+        }
+    }
+    Ok(format!("{}", output))
+}
+
+// Part2
+// Visible pixel of each position, `#` for white
+fn render (input: &str, width: usize, height: usize) -> Result<String, SolveError> {
+    let nb_pixel = width * height;
+    if nb_pixel == 0 {
+        return Err(SolveError::unsolvable("empty image size"));
+    }
+    let chars: Vec<char> = digits(input).chars().collect();
+    let nb_layer = chars.len() / nb_pixel;
+
+    let mut out = String::new();
+
+    for y in 0..height {
+        for x in 0..width {
+            for layer_index in 0..nb_layer {
+                match chars[layer_index * nb_pixel + y * width + x] {
+                    '0' => {out += " "; break},
+                    '1' => {out += "#"; break},
+                    _   => {}
+                }
+            }
+        }
+        out += "\n";
+    }
+    Ok(out)
+}
+
+pub fn part2 (input: &str, width: usize, height: usize, raw: bool) -> Result<Answer, SolveError> {
+    ocr::answer(render(input, width, height)?, raw)
+}
+
+// Solution
+const PARAMS: &[Param] = &[
+    Param { name: "width", default: 25, description: "Image width in pixels" },
+    Param { name: "height", default: 6, description: "Image height in pixels" },
+    ocr::RAW
+];
+
+pub struct Day;
+impl Solution for Day {
+    fn year (&self) -> u16 { 2019 }
+    fn day (&self) -> u8 { 8 }
+    fn title (&self) -> &'static str { "Space Image Format" }
+    fn part1 (&self, input: &str, params: &Params) -> Result<Answer, SolveError> { part1(input, params.get("width")?, params.get("height")?) }
+    fn part2 (&self, input: &str, params: &Params) -> Result<Answer, SolveError> { part2(input, params.get("width")?, params.get("height")?, ocr::raw(params)?) }
+    fn params (&self) -> &'static [Param] { PARAMS }
+    fn visual (&self, input: &str, params: &Params) -> Option<Result<String, SolveError>> {
+        Some(params.get("width").and_then(|width| render(input, width, params.get("height")?)))
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use crate::days::Solution;
+    use crate::params::Params;
+
+    #[test]
+    fn day8_part1 () {
+        assert_eq!(super::part1("0", 25, 6).unwrap(), "0");
+        assert_eq!(super::part1("123456789012", 3, 2).unwrap(), "1");
+        assert!(super::part1("123456789012", 0, 2).is_err());
+    }
+
+    #[test]
+    fn day8_part2 () {
+        assert_eq!(super::render("0222112222120000", 2, 2).unwrap(), " #\n# \n");
+    }
+
+    #[test]
+    fn day8_crlf_bom () {
+        // Called directly, without the normalization of the runner
+        let params = Params::resolve(super::PARAMS, &[(String::from("width"), 2), (String::from("height"), 2), (String::from("raw"), 1)]);
+        assert_eq!(super::Day.part1("\u{feff}0222112222120000\r\n", &params).unwrap(), "4");
+        assert_eq!(super::Day.part2("\u{feff}0222112222120000\r\n", &params).unwrap(), " #\n# \n");
+    }
+}
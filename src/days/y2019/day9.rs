@@ -0,0 +1,67 @@
+use crate::intcode::{self,Status,Machine};
+use crate::error::{Answer,SolveError};
+use super::{Params,Solution};
+
+// Part1
+pub fn part1 (input: &str) -> Result<Answer, SolveError> {
+    let opcodes = intcode::parse(input)?;
+    let mut amplifier = Machine::new(&opcodes).add_input(1);
+    let mut res = String::new();
+    loop {
+        match amplifier.step() {
+            Status::Output(output) => res += &format!("{},", output),
+            Status::Halt => break,
+            Status::WaitingForInput => return Err(SolveError::unsolvable("the program asked for more input")),
+            Status::Error(opcode) => return Err(SolveError::fault(opcode)),
+            _ => {}
+        }
+    }
+
+    res.pop();
+    Ok(res)
+}
+
+// Part2
+pub fn part2 (input: &str) -> Result<Answer, SolveError> {
+    let opcodes = intcode::parse(input)?;
+    let mut amplifier = Machine::new(&opcodes).add_input(2);
+    let mut res = String::new();
+    loop {
+        match amplifier.step() {
+            Status::Output(output) => res += &format!("{},", output),
+            Status::Halt => break,
+            Status::WaitingForInput => return Err(SolveError::unsolvable("the program asked for more input")),
+            Status::Error(opcode) => return Err(SolveError::fault(opcode)),
+            _ => {}
+        }
+    }
+
+    res.pop();
+    Ok(res)
+}
+
+// Solution
+pub struct Day;
+impl Solution for Day {
+    fn year (&self) -> u16 { 2019 }
+    fn day (&self) -> u8 { 9 }
+    fn title (&self) -> &'static str { "Sensor Boost" }
+    fn part1 (&self, input: &str, _params: &Params) -> Result<Answer, SolveError> { part1(input) }
+    fn part2 (&self, input: &str, _params: &Params) -> Result<Answer, SolveError> { part2(input) }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn day9_part1 () {
+        assert_eq!(super::part1("109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99").unwrap(), "109,1,204,-1,1001,100,1,100,1008,100,16,101,1006,101,0,99");
+        assert_eq!(super::part1("1102,34915192,34915192,7,4,7,99,0").unwrap().len(), 16);
+        assert_eq!(super::part1("104,1125899906842624,99").unwrap(), "1125899906842624");
+    }
+
+    #[test]
+    fn day9_part2 () {
+        assert_eq!(super::part1("104,1125899906842624,99").unwrap(), "1125899906842624");
+    }
+}
@@ -0,0 +1,120 @@
+use std::fmt;
+use std::str::FromStr;
+use crate::days::{self,Solution};
+use crate::intcode::loader::ParseError;
+use crate::runner::Part;
+
+pub type Answer = String;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Kind {
+    // 1-based position in the input
+    Parse { line: usize, column: usize },
+    Unsolvable,
+    Intcode,
+    // Rendered text with a letter the OCR doesn't know
+    Ocr
+}
+
+// Year, day and part are filled in by the runner, solutions only describe what went wrong
+#[derive(Debug, Clone, PartialEq)]
+pub struct SolveError {
+    pub year: Option<u16>,
+    pub day: Option<u8>,
+    pub part: Option<Part>,
+    pub kind: Kind,
+    pub message: String
+}
+impl SolveError {
+    fn new (kind: Kind, message: String) -> Self {
+        Self { year: None, day: None, part: None, kind, message }
+    }
+    pub fn parse<S: Into<String>> (line: usize, column: usize, message: S) -> Self {
+        Self::new(Kind::Parse { line, column }, message.into())
+    }
+    pub fn unsolvable<S: Into<String>> (message: S) -> Self {
+        Self::new(Kind::Unsolvable, message.into())
+    }
+    pub fn intcode<S: Into<String>> (message: S) -> Self {
+        Self::new(Kind::Intcode, message.into())
+    }
+    pub fn ocr<S: Into<String>> (message: S) -> Self {
+        Self::new(Kind::Ocr, message.into())
+    }
+    // Status::Error from a machine
+    pub fn fault (opcode: i64) -> Self {
+        Self::intcode(format!("unknown opcode {}", opcode))
+    }
+
+    pub fn at (self, solution: &dyn Solution, part: Part) -> Self {
+        Self { year: Some(solution.year()), day: Some(solution.day()), part: Some(part), ..self }
+    }
+}
+impl fmt::Display for SolveError {
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let year = self.year.unwrap_or(days::DEFAULT_YEAR);
+        match (self.day, self.part) {
+            (Some(day), Some(part)) => write!(f, "{} part {}: ", days::name(year, day), part)?,
+            (Some(day), None) => write!(f, "{}: ", days::name(year, day))?,
+            _ => {}
+        }
+        match self.kind {
+            Kind::Parse { line, column } => write!(f, "parse error at line {}, column {}: {}", line, column, self.message),
+            Kind::Unsolvable => write!(f, "unsolvable input: {}", self.message),
+            Kind::Intcode => write!(f, "intcode fault: {}", self.message),
+            Kind::Ocr => write!(f, "unreadable text: {}", self.message)
+        }
+    }
+}
+impl std::error::Error for SolveError {}
+
+impl From<ParseError> for SolveError {
+    fn from (err: ParseError) -> Self {
+        Self::parse(err.line, err.column, format!("{} (found {:?})", err.message, err.token))
+    }
+}
+
+// Parse `token`, found at `line` and `column` of the input
+pub fn number<T: FromStr> (token: &str, line: usize, column: usize) -> Result<T, SolveError> {
+    token
+        .parse()
+        .map_err(|_| SolveError::parse(line, column, format!("invalid number {:?}", token)))
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::SolveError;
+    use crate::days::{self,Solution};
+    use crate::error::Answer;
+    use crate::intcode;
+    use crate::params::Params;
+    use crate::runner::Part;
+
+    struct Later;
+    impl Solution for Later {
+        fn year (&self) -> u16 { 2020 }
+        fn day (&self) -> u8 { 3 }
+        fn title (&self) -> &'static str { "Later" }
+        fn part1 (&self, _input: &str, _params: &Params) -> Result<Answer, SolveError> { Ok(String::new()) }
+        fn part2 (&self, _input: &str, _params: &Params) -> Result<Answer, SolveError> { Ok(String::new()) }
+    }
+
+    #[test]
+    fn error_display () {
+        let err = SolveError::parse(2, 5, "invalid direction 'X'");
+        assert_eq!(err.to_string(), "parse error at line 2, column 5: invalid direction 'X'");
+        let day3 = days::find("day3").unwrap();
+        assert_eq!(err.clone().at(day3, Part::One).to_string(), "day3 part 1: parse error at line 2, column 5: invalid direction 'X'");
+        assert_eq!(err.at(&Later, Part::Two).to_string(), "2020/day3 part 2: parse error at line 2, column 5: invalid direction 'X'");
+        assert_eq!(SolveError::fault(42).to_string(), "intcode fault: unknown opcode 42");
+    }
+
+    #[test]
+    fn error_conversions () {
+        let err: SolveError = intcode::parse("1,2,\n3,x4").unwrap_err().into();
+        assert!(matches!(err.kind, super::Kind::Parse { line: 2, column: 3 }));
+        assert_eq!(super::number::<i64>("-12", 1, 1), Ok(-12));
+        assert_eq!(super::number::<usize>("1a", 4, 2), Err(SolveError::parse(4, 2, "invalid number \"1a\"")));
+    }
+}
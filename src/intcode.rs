@@ -1,4 +1,12 @@
 use std::collections::VecDeque;
+use hooks::Hooks;
+
+pub mod diff;
+pub mod hooks;
+pub mod image;
+pub mod loader;
+pub mod search;
+pub use loader::{parse,load_file,load_stdin};
 
 pub type Opcodes = Vec<i64>;
 
@@ -11,12 +19,13 @@ pub enum Status {
     Error(i64)
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Machine {
     pub pos: usize,
     pub relative_base: usize,
     pub opcodes: Vec<i64>,
-    pub input: VecDeque<i64>
+    pub input: VecDeque<i64>,
+    pub hooks: Hooks
 }
 
 impl Machine {
@@ -25,20 +34,30 @@ impl Machine {
             pos: 0,
             relative_base: 0,
             opcodes: opcodes.clone(),
-            input: VecDeque::new()
+            input: VecDeque::new(),
+            hooks: Hooks::default()
         }
     }
     fn assign_to (self: &mut Self, pos: usize, value: i64) {
+        let value = match self.hooks.write(pos, value) {
+            Some(value) => value,
+            None => return
+        };
         if self.opcodes.len() <= pos {
             self.opcodes.resize(pos + 1, 0);
         }
         self.opcodes[pos] = value;
     }
-    fn get_opcode (self: &mut Self, pos: usize) -> i64 {
+    // Instruction fetch and mode decoding, read hooks only see parameter and data reads
+    fn fetch (&self, pos: usize) -> i64 {
         *self.opcodes.get(pos).unwrap_or(&0)
     }
+    fn get_opcode (self: &mut Self, pos: usize) -> i64 {
+        let value = self.fetch(pos);
+        self.hooks.read(pos, value)
+    }
     fn get_address (self: &mut Self, shift: u32) -> i64 {
-        let mode = self.get_opcode(self.pos) / 10i64.pow(shift + 1) % 10;
+        let mode = self.fetch(self.pos) / 10i64.pow(shift + 1) % 10;
         let value = self.get_opcode(self.pos + shift as usize);
         match mode {
             0 => value,
@@ -47,7 +66,7 @@ impl Machine {
         }
     }
     fn get_param (self: &mut Self, shift: u32) -> i64 {
-        let mode = self.get_opcode(self.pos) / 10i64.pow(shift + 1) % 10;
+        let mode = self.fetch(self.pos) / 10i64.pow(shift + 1) % 10;
         let value = self.get_opcode(self.pos + shift as usize);
         match mode {
             0 => self.get_opcode(value as usize),
@@ -64,7 +83,7 @@ impl Machine {
         self
     }
     pub fn step (self: &mut Self) -> Status {
-        let opcode = self.get_opcode(self.pos) % 100;
+        let opcode = self.fetch(self.pos) % 100;
         match opcode {
             1 => { // Addition
                 let a = self.get_param(1);
@@ -149,7 +168,19 @@ impl Machine {
                 Status::Output(i)       => { return Status::Output(i) }
                 Status::WaitingForInput => { return Status::WaitingForInput }
                 Status::Halt            => { return Status::Halt },
-                Status::Error(err)      => panic!(err)
+                Status::Error(err)      => { return Status::Error(err) }
+            }
+        }
+    }
+    // Run until the machine needs more input or halts, returning every output on the way,
+    // or the opcode it faulted on
+    pub fn run_until_blocked (&mut self) -> Result<Vec<i64>, i64> {
+        let mut outputs = Vec::new();
+        loop {
+            match self.run_until_interrupted() {
+                Status::Output(o) => outputs.push(o),
+                Status::Error(opcode) => return Err(opcode),
+                _ => return Ok(outputs)
             }
         }
     }
@@ -0,0 +1,196 @@
+use std::fmt;
+use std::ops::RangeInclusive;
+use super::{Machine,Opcodes};
+use super::loader::ParseError;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    pub address: usize,
+    pub before: i64,
+    pub after: i64
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Patch {
+    pub cells: Vec<(usize, i64)>
+}
+
+// How far past the end of a program a patch may write, memory is grown up to the patched cell
+pub const MAX_GROWTH: usize = 1 << 20;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AddressError {
+    pub address: usize,
+    pub limit: usize
+}
+impl fmt::Display for AddressError {
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "patch address {} is out of bounds (at most {})", self.address, self.limit)
+    }
+}
+impl std::error::Error for AddressError {}
+
+// Diff (cells past the end of a memory read as 0, like in `Machine`)
+pub fn diff (before: &[i64], after: &[i64]) -> Vec<Change> {
+    (0..before.len().max(after.len()))
+        .filter_map(|address| {
+            let before = *before.get(address).unwrap_or(&0);
+            let after = *after.get(address).unwrap_or(&0);
+            if before == after {
+                None
+            } else {
+                Some(Change { address, before, after })
+            }
+        })
+        .collect()
+}
+
+pub fn diff_machines (before: &Machine, after: &Machine) -> Vec<Change> {
+    diff(&before.opcodes, &after.opcodes)
+}
+
+pub fn ranges (changes: &[Change]) -> Vec<RangeInclusive<usize>> {
+    let mut ranges: Vec<RangeInclusive<usize>> = Vec::new();
+    for change in changes {
+        match ranges.last_mut() {
+            Some(range) if *range.end() + 1 == change.address => {
+                *range = *range.start()..=change.address;
+            },
+            _ => ranges.push(change.address..=change.address)
+        }
+    }
+    ranges
+}
+
+pub fn summarize (changes: &[Change]) -> String {
+    let ranges = ranges(changes);
+    let mut out = format!("{} cell(s) changed in {} range(s)\n", changes.len(), ranges.len());
+    for range in ranges {
+        let values: Vec<String> = changes
+            .iter()
+            .filter(|change| range.contains(&change.address))
+            .map(|change| format!("{} -> {}", change.before, change.after))
+            .collect();
+        out += &format!("  {:>6}..={:<6} {}\n", range.start(), range.end(), values.join(", "));
+    }
+    out
+}
+
+// Patch
+impl Patch {
+    pub fn from_changes (changes: &[Change]) -> Self {
+        Self {
+            cells: changes
+                .iter()
+                .map(|change| (change.address, change.after))
+                .collect()
+        }
+    }
+
+    // Every address must be within `MAX_GROWTH` cells of the end of a program of `len` cells
+    pub fn check (&self, len: usize) -> Result<(), AddressError> {
+        let limit = len.saturating_add(MAX_GROWTH);
+        match self.cells.iter().find(|(address, _)| *address > limit) {
+            Some((address, _)) => Err(AddressError { address: *address, limit }),
+            None => Ok(())
+        }
+    }
+
+    // Nothing is written when an address is out of bounds
+    pub fn apply (&self, opcodes: &mut Opcodes) -> Result<(), AddressError> {
+        self.check(opcodes.len())?;
+        for (address, value) in &self.cells {
+            if opcodes.len() <= *address {
+                opcodes.resize(address + 1, 0);
+            }
+            opcodes[*address] = *value;
+        }
+        Ok(())
+    }
+
+    // One `address=value` per line, blank lines and `#` comments are ignored
+    pub fn parse (input: &str) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        for (index, line) in input.lines().enumerate() {
+            let content = line.split('#').next().unwrap_or("");
+            if content.trim().is_empty() {
+                continue;
+            }
+            let cell = content
+                .split_once('=')
+                .and_then(|(address, value)| Some((address.trim().parse().ok()?, value.trim().parse().ok()?)))
+                .ok_or(ParseError {
+                    line: index + 1,
+                    column: line.len() - line.trim_start().len() + 1,
+                    token: String::from(content.trim()),
+                    message: String::from("expected address=value")
+                })?;
+            cells.push(cell);
+        }
+        Ok(Self { cells })
+    }
+}
+impl fmt::Display for Patch {
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (address, value) in &self.cells {
+            writeln!(f, "{}={}", address, value)?;
+        }
+        Ok(())
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::{Change,Patch};
+    use crate::intcode::{Machine,Status};
+
+    #[test]
+    fn diff_memories () {
+        let changes = super::diff(&[1, 2, 3, 4, 5], &[1, 7, 8, 4, 5, 0, 9]);
+        assert_eq!(changes, vec![
+            Change { address: 1, before: 2, after: 7 },
+            Change { address: 2, before: 3, after: 8 },
+            Change { address: 6, before: 0, after: 9 }
+        ]);
+        assert_eq!(super::ranges(&changes), vec![1..=2, 6..=6]);
+        assert!(super::summarize(&changes).starts_with("3 cell(s) changed in 2 range(s)\n"));
+    }
+
+    #[test]
+    fn diff_machine_snapshots () {
+        let mut machine = Machine::new(&vec![1, 0, 0, 3, 1101, 5, 6, 9, 99]);
+        let snapshot = machine.clone();
+        while let Status::Ok = machine.step() {}
+        let changes = super::diff_machines(&snapshot, &machine);
+        assert_eq!(super::ranges(&changes), vec![3..=3, 9..=9]);
+
+        let patch = Patch::from_changes(&changes);
+        let mut fresh = snapshot.opcodes.clone();
+        patch.apply(&mut fresh).unwrap();
+        assert_eq!(fresh, machine.opcodes);
+    }
+
+    #[test]
+    fn patch_bounds () {
+        let mut opcodes = vec![1, 2, 3];
+        let err = Patch::parse("0=7\n18446744073709551615=1").unwrap().apply(&mut opcodes).unwrap_err();
+        assert_eq!(err.address, usize::MAX);
+        assert_eq!(opcodes, vec![1, 2, 3]);
+
+        let far = Patch { cells: vec![(3 + super::MAX_GROWTH, 5)] };
+        assert!(far.apply(&mut opcodes).is_ok());
+        assert_eq!(opcodes.len(), 4 + super::MAX_GROWTH);
+        assert!(Patch { cells: vec![(4 + 2 * super::MAX_GROWTH, 5)] }.check(3).is_err());
+    }
+
+    #[test]
+    fn patch_text () {
+        let patch = Patch::parse("# score cell\n386=42\n\n  0 = 2 # free play\n").unwrap();
+        assert_eq!(patch.cells, vec![(386, 42), (0, 2)]);
+        assert_eq!(Patch::parse(&patch.to_string()).unwrap(), patch);
+
+        let err = Patch::parse("1=2\n  3:4").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+    }
+}
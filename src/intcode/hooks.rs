@@ -0,0 +1,166 @@
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::sync::{Arc,Mutex};
+
+// What a hook decides for the access it intercepted.
+// A vetoed read sees 0 (like unmapped memory), a vetoed write is dropped.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Action {
+    Allow,
+    Veto,
+    Substitute(i64)
+}
+
+type Callback = Arc<Mutex<dyn FnMut(usize, i64) -> Action + Send>>;
+
+#[derive(Clone)]
+struct Hook {
+    range: RangeInclusive<usize>,
+    callback: Callback
+}
+
+// Hooks are shared (not copied) when a `Machine` is cloned: every clone, like the forks of a
+// search, calls the same callbacks and so updates the same captured state. To watch a single
+// clone, use `Hooks::default()` on it and register its own hooks.
+// Read hooks see the parameter and data reads, not the instruction fetch.
+#[derive(Clone, Default)]
+pub struct Hooks {
+    reads: Vec<Hook>,
+    writes: Vec<Hook>
+}
+impl fmt::Debug for Hooks {
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let ranges = |hooks: &Vec<Hook>| hooks.iter().map(|hook| hook.range.clone()).collect::<Vec<_>>();
+        f.debug_struct("Hooks")
+            .field("reads", &ranges(&self.reads))
+            .field("writes", &ranges(&self.writes))
+            .finish()
+    }
+}
+
+// Helper
+fn run (hooks: &[Hook], address: usize, value: i64) -> Option<i64> {
+    let mut value = value;
+    for hook in hooks.iter().filter(|hook| hook.range.contains(&address)) {
+        // A callback that panicked leaves its lock poisoned, its state is still usable
+        let mut callback = hook.callback.lock().unwrap_or_else(|err| err.into_inner());
+        match (*callback)(address, value) {
+            Action::Allow => {},
+            Action::Veto => return None,
+            Action::Substitute(substitute) => value = substitute
+        }
+    }
+    Some(value)
+}
+
+impl Hooks {
+    pub fn on_read<F> (&mut self, range: RangeInclusive<usize>, callback: F)
+    where F: FnMut(usize, i64) -> Action + Send + 'static {
+        self.reads.push(Hook { range, callback: Arc::new(Mutex::new(callback)) });
+    }
+
+    pub fn on_write<F> (&mut self, range: RangeInclusive<usize>, callback: F)
+    where F: FnMut(usize, i64) -> Action + Send + 'static {
+        self.writes.push(Hook { range, callback: Arc::new(Mutex::new(callback)) });
+    }
+
+    // Value seen by the machine when reading `value` at `address`
+    pub fn read (&self, address: usize, value: i64) -> i64 {
+        if self.reads.is_empty() {
+            return value;
+        }
+        run(&self.reads, address, value).unwrap_or(0)
+    }
+
+    // Value to store when the machine writes `value` at `address`, `None` if vetoed
+    pub fn write (&self, address: usize, value: i64) -> Option<i64> {
+        if self.writes.is_empty() {
+            return Some(value);
+        }
+        run(&self.writes, address, value)
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc,Mutex};
+    use std::thread;
+    use super::Action;
+    use crate::intcode::{Machine,Status};
+
+    fn outputs (machine: &mut Machine) -> Vec<i64> {
+        let mut outputs = Vec::new();
+        loop {
+            match machine.step() {
+                Status::Output(o) => outputs.push(o),
+                Status::Ok => {},
+                _ => return outputs
+            }
+        }
+    }
+
+    #[test]
+    fn hooks_observe () {
+        // Count down cell 20 from 3, outputting it each time
+        let opcodes = vec![1001, 20, -1, 20, 4, 20, 1005, 20, 0, 99];
+        let mut machine = Machine::new(&opcodes);
+        machine.opcodes.resize(21, 0);
+        machine.opcodes[20] = 3;
+        let written = Arc::new(Mutex::new(Vec::new()));
+        let mirror = written.clone();
+        machine.hooks.on_write(20..=20, move |_, value| {
+            mirror.lock().unwrap().push(value);
+            Action::Allow
+        });
+        assert_eq!(outputs(&mut machine), vec![2, 1, 0]);
+        assert_eq!(*written.lock().unwrap(), vec![2, 1, 0]);
+    }
+
+    #[test]
+    fn hooks_data_reads_only () {
+        // Output cell 0: the fetch of the instruction in cell 0 is not a read of it
+        let mut machine = Machine::new(&vec![4, 0, 99]);
+        let reads = Arc::new(Mutex::new(0));
+        let counter = reads.clone();
+        machine.hooks.on_read(0..=0, move |_, _| {
+            *counter.lock().unwrap() += 1;
+            Action::Allow
+        });
+        assert_eq!(outputs(&mut machine), vec![4]);
+        assert_eq!(*reads.lock().unwrap(), 1);
+    }
+
+    #[test]
+    fn hooks_shared_between_clones () {
+        // Machines with hooks can move to other threads, their clones share the hooks
+        let mut machine = Machine::new(&vec![4, 0, 99]);
+        let reads = Arc::new(Mutex::new(0));
+        let counter = reads.clone();
+        machine.hooks.on_read(0..=0, move |_, _| {
+            *counter.lock().unwrap() += 1;
+            Action::Allow
+        });
+        let mut fork = machine.clone();
+        let fork_outputs = thread::spawn(move || outputs(&mut fork)).join().unwrap();
+        assert_eq!(fork_outputs, outputs(&mut machine));
+        assert_eq!(*reads.lock().unwrap(), 2);
+    }
+
+    #[test]
+    fn hooks_substitute_and_veto () {
+        // Output cell 9, overwrite it with 7, output it again
+        let opcodes = vec![4, 9, 1101, 3, 4, 9, 4, 9, 99, 5];
+        let mut machine = Machine::new(&opcodes);
+        machine.hooks.on_read(9..=9, |_, value| Action::Substitute(value * 100));
+        assert_eq!(outputs(&mut machine), vec![500, 700]);
+
+        let mut machine = Machine::new(&opcodes);
+        machine.hooks.on_write(9..=9, |_, _| Action::Veto);
+        assert_eq!(outputs(&mut machine), vec![5, 5]);
+
+        let mut machine = Machine::new(&opcodes);
+        machine.hooks.on_read(9..=9, |_, _| Action::Veto);
+        assert_eq!(outputs(&mut machine), vec![0, 0]);
+    }
+}
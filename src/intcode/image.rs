@@ -0,0 +1,219 @@
+use std::fmt;
+use super::Opcodes;
+use super::diff::{AddressError,Patch};
+
+// Layout (all integers are LEB128 varints, signed ones zigzag-encoded first):
+//   magic "ICIM" | version | nb_patches | (address, value)* | nb_words | word* | fnv1a-32 checksum (LE)
+pub const MAGIC: &[u8; 4] = b"ICIM";
+pub const VERSION: u8 = 1;
+
+#[derive(Debug, PartialEq)]
+pub enum ImageError {
+    BadMagic,
+    UnsupportedVersion(u8),
+    Truncated,
+    Overflow,
+    BadChecksum { expected: u32, found: u32 },
+    TrailingBytes(usize),
+    BadAddress(usize)
+}
+impl fmt::Display for ImageError {
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ImageError::BadMagic => write!(f, "not an intcode image (bad magic)"),
+            ImageError::UnsupportedVersion(v) => write!(f, "unsupported image version {}", v),
+            ImageError::Truncated => write!(f, "image is truncated"),
+            ImageError::Overflow => write!(f, "varint does not fit in 64 bits"),
+            ImageError::BadChecksum { expected, found } => write!(f, "bad checksum: expected {:08x}, found {:08x}", expected, found),
+            ImageError::TrailingBytes(n) => write!(f, "{} unexpected bytes after checksum", n),
+            ImageError::BadAddress(address) => write!(f, "patch address {} is out of bounds", address)
+        }
+    }
+}
+impl std::error::Error for ImageError {}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Image {
+    pub opcodes: Opcodes,
+    pub patch: Patch
+}
+
+// Helper
+fn fnv1a (bytes: &[u8]) -> u32 {
+    bytes
+        .iter()
+        .fold(0x811c_9dc5u32, |hash, b| (hash ^ *b as u32).wrapping_mul(0x0100_0193))
+}
+fn zigzag (value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+fn unzigzag (value: u64) -> i64 {
+    (value >> 1) as i64 ^ -((value & 1) as i64)
+}
+fn write_varint (out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize
+}
+impl<'a> Reader<'a> {
+    fn byte (&mut self) -> Result<u8, ImageError> {
+        let byte = *self.bytes.get(self.pos).ok_or(ImageError::Truncated)?;
+        self.pos += 1;
+        Ok(byte)
+    }
+    fn varint (&mut self) -> Result<u64, ImageError> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let byte = self.byte()?;
+            if shift == 63 && byte > 1 {
+                return Err(ImageError::Overflow);
+            }
+            value |= ((byte & 0x7f) as u64) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(ImageError::Overflow)
+    }
+    fn length (&mut self) -> Result<usize, ImageError> {
+        let length = self.varint()? as usize;
+        // Every entry takes at least one byte, so a larger count can only be a corrupt image
+        if length > self.bytes.len() - self.pos {
+            return Err(ImageError::Truncated);
+        }
+        Ok(length)
+    }
+}
+
+impl Image {
+    pub fn new (opcodes: &[i64]) -> Self {
+        Self {
+            opcodes: opcodes.to_vec(),
+            patch: Patch::default()
+        }
+    }
+
+    pub fn with_patch (mut self, address: usize, value: i64) -> Self {
+        self.patch.cells.push((address, value));
+        self
+    }
+
+    pub fn patched (&self) -> Result<Opcodes, AddressError> {
+        let mut opcodes = self.opcodes.clone();
+        self.patch.apply(&mut opcodes)?;
+        Ok(opcodes)
+    }
+
+    pub fn encode (&self) -> Vec<u8> {
+        let mut out = MAGIC.to_vec();
+        out.push(VERSION);
+        write_varint(&mut out, self.patch.cells.len() as u64);
+        for (address, value) in &self.patch.cells {
+            write_varint(&mut out, *address as u64);
+            write_varint(&mut out, zigzag(*value));
+        }
+        write_varint(&mut out, self.opcodes.len() as u64);
+        for word in &self.opcodes {
+            write_varint(&mut out, zigzag(*word));
+        }
+        let checksum = fnv1a(&out);
+        out.extend_from_slice(&checksum.to_le_bytes());
+        out
+    }
+
+    pub fn decode (bytes: &[u8]) -> Result<Self, ImageError> {
+        if !is_image(bytes) {
+            return Err(ImageError::BadMagic);
+        }
+        let mut reader = Reader { bytes, pos: MAGIC.len() };
+        let version = reader.byte()?;
+        if version != VERSION {
+            return Err(ImageError::UnsupportedVersion(version));
+        }
+
+        let mut patch = Patch::default();
+        for _ in 0..reader.length()? {
+            let address = reader.varint()? as usize;
+            let value = unzigzag(reader.varint()?);
+            patch.cells.push((address, value));
+        }
+        let mut opcodes = Opcodes::new();
+        for _ in 0..reader.length()? {
+            opcodes.push(unzigzag(reader.varint()?));
+        }
+
+        let end = reader.pos;
+        let mut found = [0u8; 4];
+        for byte in found.iter_mut() {
+            *byte = reader.byte()?;
+        }
+        let expected = fnv1a(&bytes[..end]);
+        let found = u32::from_le_bytes(found);
+        if expected != found {
+            return Err(ImageError::BadChecksum { expected, found });
+        }
+        if reader.pos != bytes.len() {
+            return Err(ImageError::TrailingBytes(bytes.len() - reader.pos));
+        }
+        // Patches are applied when loading, a hostile address must not get to grow the memory
+        patch.check(opcodes.len()).map_err(|err| ImageError::BadAddress(err.address))?;
+
+        Ok(Self { opcodes, patch })
+    }
+}
+
+pub fn is_image (bytes: &[u8]) -> bool {
+    bytes.starts_with(MAGIC)
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::{Image,ImageError};
+
+    #[test]
+    fn image_roundtrip () {
+        let image = Image::new(&[1, 0, 0, 3, 99, -1, i64::MIN, i64::MAX, 1125899906842624])
+            .with_patch(0, 2)
+            .with_patch(12, -7);
+        let bytes = image.encode();
+        assert_eq!(&bytes[..5], b"ICIM\x01");
+        assert_eq!(Image::decode(&bytes), Ok(image.clone()));
+        let patched = image.patched().unwrap();
+        assert_eq!(patched[..5], [2, 0, 0, 3, 99]);
+        assert_eq!(patched.len(), 13);
+        assert_eq!(patched[12], -7);
+        assert!(image.with_patch(usize::MAX, 1).patched().is_err());
+    }
+
+    #[test]
+    fn image_errors () {
+        let bytes = Image::new(&[104, 1, 99]).encode();
+        assert_eq!(Image::decode(b"1,2,3"), Err(ImageError::BadMagic));
+        assert_eq!(Image::decode(&bytes[..bytes.len() - 2]), Err(ImageError::Truncated));
+
+        let mut corrupted = bytes.clone();
+        corrupted[7] ^= 1;
+        assert!(matches!(Image::decode(&corrupted), Err(ImageError::BadChecksum { .. })));
+
+        let mut newer = bytes.clone();
+        newer[4] = 2;
+        assert_eq!(Image::decode(&newer), Err(ImageError::UnsupportedVersion(2)));
+    }
+
+    #[test]
+    fn image_hostile_address () {
+        let hostile = Image::new(&[99]).with_patch(usize::MAX, 1).encode();
+        assert_eq!(Image::decode(&hostile), Err(ImageError::BadAddress(usize::MAX)));
+        assert!(crate::intcode::loader::load_bytes(&hostile).is_err());
+
+        let far = Image::new(&[99]).with_patch(1 << 40, 1).encode();
+        assert_eq!(Image::decode(&far), Err(ImageError::BadAddress(1 << 40)));
+    }
+}
@@ -0,0 +1,196 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, Read};
+use std::path::Path;
+use super::Opcodes;
+use super::diff::AddressError;
+use super::image::{self,Image,ImageError};
+
+#[derive(Debug)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub token: String,
+    pub message: String
+}
+impl fmt::Display for ParseError {
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, column {}: {} (found {:?})", self.line, self.column, self.message, self.token)
+    }
+}
+impl std::error::Error for ParseError {}
+
+#[derive(Debug)]
+pub enum LoadError {
+    Io(io::Error),
+    Parse(ParseError),
+    Image(ImageError),
+    Patch(AddressError)
+}
+impl fmt::Display for LoadError {
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            LoadError::Io(err) => write!(f, "cannot read program: {}", err),
+            LoadError::Parse(err) => write!(f, "cannot parse program: {}", err),
+            LoadError::Image(err) => write!(f, "cannot decode image: {}", err),
+            LoadError::Patch(err) => write!(f, "cannot patch image: {}", err)
+        }
+    }
+}
+impl std::error::Error for LoadError {}
+impl From<io::Error> for LoadError {
+    fn from (err: io::Error) -> Self {
+        LoadError::Io(err)
+    }
+}
+impl From<ParseError> for LoadError {
+    fn from (err: ParseError) -> Self {
+        LoadError::Parse(err)
+    }
+}
+impl From<ImageError> for LoadError {
+    fn from (err: ImageError) -> Self {
+        LoadError::Image(err)
+    }
+}
+impl From<AddressError> for LoadError {
+    fn from (err: AddressError) -> Self {
+        LoadError::Patch(err)
+    }
+}
+
+// Helper
+struct Token {
+    line: usize,
+    column: usize,
+    text: String
+}
+fn error (token: &Token, message: &str) -> ParseError {
+    ParseError {
+        line: token.line,
+        column: token.column,
+        token: token.text.clone(),
+        message: String::from(message)
+    }
+}
+
+// Split the program into values and commas, skipping whitespace and `#` comments
+fn tokenize (input: &str) -> Vec<Token> {
+    let mut tokens: Vec<Token> = Vec::new();
+    let mut current: Option<Token> = None;
+    let mut in_comment = false;
+    let mut line = 1;
+    let mut column = 0;
+
+    for c in input.trim_start_matches('\u{feff}').chars() {
+        column += 1;
+        if c == ',' || c == '#' || c.is_whitespace() {
+            if let Some(token) = current.take() {
+                tokens.push(token);
+            }
+        }
+
+        match c {
+            '\n' => {
+                in_comment = false;
+                line += 1;
+                column = 0;
+            },
+            _ if in_comment => {},
+            '#' => in_comment = true,
+            ',' => tokens.push(Token { line, column, text: String::from(",") }),
+            c if c.is_whitespace() => {},
+            c => current
+                .get_or_insert(Token { line, column, text: String::new() })
+                .text
+                .push(c)
+        }
+    }
+    if let Some(token) = current.take() {
+        tokens.push(token);
+    }
+
+    tokens
+}
+
+// Parse
+pub fn parse (input: &str) -> Result<Opcodes, ParseError> {
+    let mut opcodes = Opcodes::new();
+    let mut expect_value = true;
+
+    for token in tokenize(input) {
+        if token.text == "," {
+            if expect_value {
+                return Err(error(&token, "expected a value before ','"));
+            }
+            expect_value = true;
+        } else {
+            if !expect_value {
+                return Err(error(&token, "expected ',' between values"));
+            }
+            let value = token.text
+                .parse()
+                .map_err(|_| error(&token, "invalid integer"))?;
+            opcodes.push(value);
+            expect_value = false;
+        }
+    }
+
+    Ok(opcodes)
+}
+
+// Load (text programs or binary images, with their patches applied)
+pub fn load_bytes (bytes: &[u8]) -> Result<Opcodes, LoadError> {
+    if image::is_image(bytes) {
+        return Ok(Image::decode(bytes)?.patched()?);
+    }
+    let content = String::from_utf8_lossy(bytes);
+    Ok(parse(&content)?)
+}
+
+pub fn load_file<P: AsRef<Path>> (path: P) -> Result<Opcodes, LoadError> {
+    load_bytes(&fs::read(path)?)
+}
+
+pub fn load_stdin () -> Result<Opcodes, LoadError> {
+    let mut content = Vec::new();
+    io::stdin().read_to_end(&mut content)?;
+    load_bytes(&content)
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn parse_plain () {
+        assert_eq!(super::parse("1,0,0,3,99").unwrap(), vec![1, 0, 0, 3, 99]);
+        assert_eq!(super::parse("104,-5,99\n").unwrap(), vec![104, -5, 99]);
+        assert_eq!(super::parse("").unwrap(), vec![]);
+    }
+
+    #[test]
+    fn parse_layout () {
+        assert_eq!(super::parse("1,0,\r\n0,3,\r\n99,\r\n").unwrap(), vec![1, 0, 0, 3, 99]);
+        assert_eq!(super::parse("\u{feff}  3, 98,\n\t104, 1,\n  99").unwrap(), vec![3, 98, 104, 1, 99]);
+        assert_eq!(super::parse("# header\n1,2, # add\n3,4 #tail,5\n").unwrap(), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn parse_errors () {
+        let err = super::parse("1,2,\n3,x4,5").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 3, "x4"));
+
+        let err = super::parse("1,,2").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (1, 3, ","));
+
+        let err = super::parse("1,2\n3").unwrap_err();
+        assert_eq!((err.line, err.column, err.token.as_str()), (2, 1, "3"));
+    }
+
+    #[test]
+    fn load_image_bytes () {
+        let image = super::Image::new(&[1, 0, 0, 3, 99]).with_patch(1, 4);
+        assert_eq!(super::load_bytes(&image.encode()).unwrap(), vec![1, 4, 0, 3, 99]);
+        assert_eq!(super::load_bytes(b"1,0,0,3,99\n").unwrap(), vec![1, 0, 0, 3, 99]);
+    }
+}
@@ -0,0 +1,198 @@
+use std::cmp::{Ordering,Reverse};
+use std::collections::{BinaryHeap,HashSet,VecDeque};
+use std::hash::Hash;
+use super::Machine;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Strategy {
+    Bfs,
+    Dfs,
+    AStar
+}
+
+// A point of the search: a snapshot of the machine, the host state built around it,
+// and the cost to reach it from the start
+#[derive(Debug, Clone)]
+pub struct Node<S> {
+    pub machine: Machine,
+    pub state: S,
+    pub cost: usize
+}
+impl<S> Node<S> {
+    pub fn new (machine: Machine, state: S) -> Self {
+        Self { machine, state, cost: 0 }
+    }
+
+    // Clone the machine and feed it `inputs`, returning the copy and what it output until blocked,
+    // or the opcode it faulted on
+    pub fn fork (&self, inputs: &[i64]) -> Result<(Machine, Vec<i64>), i64> {
+        let mut machine = self.machine.clone();
+        for input in inputs {
+            machine.add_input_mut(*input);
+        }
+        let outputs = machine.run_until_blocked()?;
+        Ok((machine, outputs))
+    }
+
+    pub fn next (&self, machine: Machine, state: S, cost: usize) -> Self {
+        Self { machine, state, cost: self.cost + cost }
+    }
+}
+
+// Helper
+struct Entry<S> {
+    priority: usize,
+    order: usize,
+    node: Node<S>
+}
+impl<S> Ord for Entry<S> {
+    fn cmp (&self, other: &Self) -> Ordering {
+        (Reverse(self.priority), Reverse(self.order)).cmp(&(Reverse(other.priority), Reverse(other.order)))
+    }
+}
+impl<S> PartialOrd for Entry<S> {
+    fn partial_cmp (&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl<S> PartialEq for Entry<S> {
+    fn eq (&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+impl<S> Eq for Entry<S> {}
+
+enum Frontier<S> {
+    Queue(VecDeque<Node<S>>),
+    Stack(Vec<Node<S>>),
+    Heap(BinaryHeap<Entry<S>>, usize)
+}
+impl<S> Frontier<S> {
+    fn push (&mut self, node: Node<S>, heuristic: usize) {
+        match self {
+            Frontier::Queue(queue) => queue.push_back(node),
+            Frontier::Stack(stack) => stack.push(node),
+            Frontier::Heap(heap, order) => {
+                *order += 1;
+                heap.push(Entry { priority: node.cost + heuristic, order: *order, node });
+            }
+        }
+    }
+    fn pop (&mut self) -> Option<Node<S>> {
+        match self {
+            Frontier::Queue(queue) => queue.pop_front(),
+            Frontier::Stack(stack) => stack.pop(),
+            Frontier::Heap(heap, _) => heap.pop().map(|entry| entry.node)
+        }
+    }
+}
+
+pub struct Search<S, K> {
+    key: Box<dyn Fn(&S) -> K>,
+    heuristic: Box<dyn Fn(&S) -> usize>
+}
+impl<S, K: Hash + Eq> Search<S, K> {
+    // Two nodes with the same key are the same point of the search, only the first reached is expanded
+    pub fn new<F> (key: F) -> Self
+    where F: Fn(&S) -> K + 'static {
+        Self {
+            key: Box::new(key),
+            heuristic: Box::new(|_| 0)
+        }
+    }
+
+    // Lower bound of the remaining cost, only used by A*
+    pub fn heuristic<F> (mut self, heuristic: F) -> Self
+    where F: Fn(&S) -> usize + 'static {
+        self.heuristic = Box::new(heuristic);
+        self
+    }
+
+    // Expand nodes from `start` until `visit` returns true for one of them (which is returned)
+    // or the state space is exhausted, stopping at the first error of `expand`
+    pub fn run<E, V, Err> (&self, strategy: Strategy, start: Node<S>, mut expand: E, mut visit: V) -> Result<Option<Node<S>>, Err>
+    where E: FnMut(&Node<S>) -> Result<Vec<Node<S>>, Err>,
+          V: FnMut(&Node<S>) -> bool {
+        let mut frontier = match strategy {
+            Strategy::Bfs => Frontier::Queue(VecDeque::new()),
+            Strategy::Dfs => Frontier::Stack(Vec::new()),
+            Strategy::AStar => Frontier::Heap(BinaryHeap::new(), 0)
+        };
+        let mut seen = HashSet::new();
+        let heuristic = (self.heuristic)(&start.state);
+        frontier.push(start, heuristic);
+
+        while let Some(node) = frontier.pop() {
+            // With A*, a cheaper path to a key may be found after it was pushed
+            if !seen.insert((self.key)(&node.state)) {
+                continue;
+            }
+            if visit(&node) {
+                return Ok(Some(node));
+            }
+            for next in expand(&node)? {
+                if !seen.contains(&(self.key)(&next.state)) {
+                    let heuristic = (self.heuristic)(&next.state);
+                    frontier.push(next, heuristic);
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::{Node,Search,Strategy};
+    use crate::intcode::Machine;
+
+    // Echo the input, forever
+    fn echo () -> Machine {
+        Machine::new(&vec![3, 7, 4, 7, 1105, 1, 0])
+    }
+
+    // Reach `target` from 1 with `+1` and `*2` moves, the machine echoing back the chosen step
+    fn moves (node: &Node<i64>) -> Result<Vec<Node<i64>>, i64> {
+        let mut nexts = Vec::new();
+        for step in &[1, 2] {
+            let (machine, outputs) = node.fork(&[*step])?;
+            let state = if outputs[0] == 1 { node.state + 1 } else { node.state * 2 };
+            if state <= 100 {
+                nexts.push(node.next(machine, state, 1));
+            }
+        }
+        Ok(nexts)
+    }
+
+    #[test]
+    fn search_strategies () {
+        let search = Search::new(|n: &i64| *n);
+        let bfs = search.run(Strategy::Bfs, Node::new(echo(), 1), moves, |node| node.state == 37).unwrap().unwrap();
+        assert_eq!(bfs.cost, 7);
+
+        let dfs = search.run(Strategy::Dfs, Node::new(echo(), 1), moves, |node| node.state == 37).unwrap().unwrap();
+        assert!(dfs.cost >= 7);
+
+        let search = search.heuristic(|n: &i64| if *n == 37 { 0 } else { 1 });
+        let astar = search.run(Strategy::AStar, Node::new(echo(), 1), moves, |node| node.state == 37).unwrap().unwrap();
+        assert_eq!(astar.cost, 7);
+    }
+
+    #[test]
+    fn search_exhaustive () {
+        let mut count = 0;
+        let search = Search::new(|n: &i64| *n);
+        let found = search.run(Strategy::Bfs, Node::new(echo(), 1), moves, |_| { count += 1; false });
+        assert!(found.unwrap().is_none());
+        assert_eq!(count, 100);
+    }
+
+    #[test]
+    fn search_fault () {
+        // Echo the input once, then fault on opcode 0
+        let faulty = Machine::new(&vec![3, 5, 4, 5, 0]);
+        let search = Search::new(|n: &i64| *n);
+        assert_eq!(search.run(Strategy::Bfs, Node::new(faulty, 1), moves, |_| false).unwrap_err(), 0);
+    }
+}
@@ -0,0 +1,36 @@
+// Advent of Code 2019 solutions, usable from other crates
+// `intcode` is the Intcode machine and its tooling, `days` the registered solutions and `runner` runs them
+// The hidden modules (`cli`, `commands`, `report`, `scaffold`, `watch`) serve the binary and aren't a stable API
+pub mod answers;
+pub mod bench;
+pub mod cache;
+#[doc(hidden)]
+pub mod cli;
+#[doc(hidden)]
+pub mod commands;
+pub mod crosscheck;
+pub mod days;
+pub mod error;
+pub mod intcode;
+pub mod memory;
+pub mod ocr;
+pub mod params;
+pub mod progress;
+#[doc(hidden)]
+pub mod report;
+pub mod runner;
+#[doc(hidden)]
+pub mod scaffold;
+#[doc(hidden)]
+pub mod watch;
+
+pub use days::Solution;
+pub use error::{Answer,SolveError};
+pub use intcode::{Machine,Opcodes};
+pub use params::{Param,Params};
+pub use runner::{Part,Run,Source,Status};
+
+// The binary installs the counting allocator, the unit tests need it too for `--memory`
+#[cfg(test)]
+#[global_allocator]
+static ALLOCATOR: memory::Counting = memory::Counting;
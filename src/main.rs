@@ -1,60 +1,37 @@
 use std::env;
 use std::process;
-use std::fs;
-use regex::Regex;
-use std::time::Instant;
+use std::panic;
+use advent_of_code_2019::{cli,commands,memory,runner};
 
-mod days;
-mod intcode;
+// Counts allocations per thread once `--memory` asks for it
+#[global_allocator]
+static ALLOCATOR: memory::Counting = memory::Counting;
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
-
-    // Check given day
-    if args.len() <= 1 {
-        println!("Please provide a day");
-        process::exit(1)
-    }
-
-    // Check if given day is valid
-    let day: &String = &args[1];
-    let day_regex = Regex::new(r"^day([1-9]|1[0-9]|2[0-5])$").unwrap();
-    if !day_regex.is_match(day) {
-        println!("Please provide a valid day");
-        process::exit(1)
-    }
-
-    // Load file
-    let filename: String = format!("./inputs/{}.txt", day);
-    println!("{}", filename);
-    let file_input = fs::read_to_string(filename).expect("cannot read file");
-
-    // Retrieve input
-    let input = if args.len() > 2 {
-        // Use Args as input for day
-        &args[2]
-    } else {
-        &file_input
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    // Panics of the parts are reported with their runs, any other keeps the default output
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| if !runner::catching() { default_hook(info) }));
+
+    let result = match args.first().map(String::as_str) {
+        None => Err(String::from("Please provide a day")),
+        // Intcode image conversion
+        Some("image") => commands::image_command(&args[1..]).map(|_| true),
+        // New day from the template
+        Some("new") => commands::new_command(&args[1..]).map(|_| true),
+        // Re-run a day on changes, options apply to the watched day
+        Some("watch") => cli::parse(&args[1..]).and_then(|options| commands::watch_command(&options)).map(|_| true),
+        Some(_) => cli::parse(&args).and_then(|options| commands::run(&options))
     };
-    println!("{}\n", input);
 
-
-    // Part1
-    print!("Part1: ");
-    let start1 = Instant::now();
-    let result_part1 = days::part1(day, &input);
-    let duration1 = start1.elapsed();
-    println!("({:?})", duration1);
-    println!("{}\n", result_part1);
-
-
-    // Part2
-    print!("Part2: ");
-    let start2 = Instant::now();
-    let result_part2 = days::part2(day, &input);
-    let duration2 = start2.elapsed();
-    println!("({:?})", duration2);
-    println!("{}\n", result_part2);
-
-    println!("Time elapsed: {:?}", duration1 + duration2);
+    match result {
+        Ok(true) => {},
+        Ok(false) => process::exit(1),
+        // On stderr, stdout may be read as JSON
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(1)
+        }
+    }
 }
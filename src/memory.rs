@@ -0,0 +1,118 @@
+use std::alloc::{GlobalAlloc,Layout,System};
+use std::cell::Cell;
+use std::hint;
+use std::sync::atomic::{AtomicBool,Ordering};
+
+// Global allocator counting the bytes allocated by each thread, parts run on their own thread
+// so the peak of a thread while it runs a part is the peak memory of that part.
+// Counting only starts with the first `reset_peak`, until then an allocation costs two relaxed
+// atomic loads more than with the system allocator.
+pub struct Counting;
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+static COUNTING: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static CURRENT: Cell<isize> = const { Cell::new(0) };
+    static PEAK: Cell<isize> = const { Cell::new(0) };
+}
+
+// Helper
+fn add (bytes: isize) {
+    if !INSTALLED.load(Ordering::Relaxed) {
+        INSTALLED.store(true, Ordering::Relaxed);
+    }
+    if !COUNTING.load(Ordering::Relaxed) {
+        return;
+    }
+    // `try_with` as allocations can happen while the thread is being torn down
+    let _ = CURRENT.try_with(|current| {
+        let value = current.get() + bytes;
+        current.set(value);
+        let _ = PEAK.try_with(|peak| if value > peak.get() { peak.set(value) });
+    });
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc (&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            add(layout.size() as isize);
+        }
+        ptr
+    }
+    unsafe fn alloc_zeroed (&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            add(layout.size() as isize);
+        }
+        ptr
+    }
+    unsafe fn dealloc (&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        add(-(layout.size() as isize));
+    }
+    unsafe fn realloc (&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            add(new_size as isize - layout.size() as isize);
+        }
+        new_ptr
+    }
+}
+
+// Whether `Counting` is the global allocator, nothing can be measured without it
+pub fn available () -> bool {
+    if !INSTALLED.load(Ordering::Relaxed) {
+        drop(hint::black_box(Box::new(0u8)));
+    }
+    INSTALLED.load(Ordering::Relaxed)
+}
+
+// Start measuring on the current thread (and counting on all of them), returns the baseline to give to `peak_since`
+pub fn reset_peak () -> isize {
+    COUNTING.store(true, Ordering::Relaxed);
+    let current = CURRENT.with(|current| current.get());
+    PEAK.with(|peak| peak.set(current));
+    current
+}
+
+// Peak allocated by the current thread since `reset_peak`, `None` without the counting allocator
+pub fn peak_since (baseline: isize) -> Option<usize> {
+    let peak = PEAK.with(|peak| (peak.get() - baseline).max(0) as usize);
+    if available() { Some(peak) } else { None }
+}
+
+pub fn format (bytes: usize) -> String {
+    match bytes {
+        b if b >= 1 << 30 => format!("{:.2} GiB", b as f64 / (1u64 << 30) as f64),
+        b if b >= 1 << 20 => format!("{:.2} MiB", b as f64 / (1u64 << 20) as f64),
+        b if b >= 1 << 10 => format!("{:.2} KiB", b as f64 / (1u64 << 10) as f64),
+        b => format!("{} B", b)
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn memory_peak () {
+        let baseline = super::reset_peak();
+        let buffer: Vec<u8> = Vec::with_capacity(1 << 20);
+        drop(buffer);
+        let small: Vec<u8> = Vec::with_capacity(16);
+        assert!(super::peak_since(baseline).unwrap() >= 1 << 20);
+        drop(small);
+
+        let baseline = super::reset_peak();
+        assert_eq!(super::peak_since(baseline), Some(0));
+        assert!(super::available());
+    }
+
+    #[test]
+    fn memory_format () {
+        assert_eq!(super::format(512), "512 B");
+        assert_eq!(super::format(1536), "1.50 KiB");
+        assert_eq!(super::format(3 << 20), "3.00 MiB");
+    }
+}
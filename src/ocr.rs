@@ -0,0 +1,129 @@
+use crate::error::{Answer,SolveError};
+use crate::params::{Param,Params};
+
+// Block letters of the puzzles, 6 rows high and 4 (or 5) columns wide
+const FONT: &[(char, &str)] = &[
+    ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+    ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+    ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+    ('E', "####\n#...\n###.\n#...\n#...\n####"),
+    ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+    ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+    ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+    ('I', "###\n.#.\n.#.\n.#.\n.#.\n###"),
+    ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+    ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+    ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+    ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+    ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+    ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+    ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+    ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+    ('Z', "####\n...#\n..#.\n.#..\n#...\n####")
+];
+
+// Parameter of the days rendering their answer, set by `--raw`
+pub const RAW: Param = Param { name: "raw", default: 0, description: "Keep the rendered text instead of reading its letters (0 or 1)" };
+
+pub fn raw (params: &Params) -> Result<bool, SolveError> {
+    Ok(params.get::<i64>(RAW.name)? != 0)
+}
+
+// Helper
+// Lit pixels of a glyph as `#`, everything else as `.`, one line per row
+fn pattern (rows: &[Vec<bool>], columns: std::ops::Range<usize>) -> String {
+    rows.iter()
+        .map(|row| columns.clone().map(|x| if row.get(x) == Some(&true) { '#' } else { '.' }).collect::<String>())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+// Read the letters of a rendering (`#` for lit pixels), glyphs being separated by blank columns
+pub fn read (art: &str) -> Result<String, SolveError> {
+    let rows: Vec<Vec<bool>> = art
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.chars().map(|c| c == '#').collect())
+        .collect();
+    if rows.len() != 6 {
+        return Err(SolveError::ocr(format!("expected 6 rows of letters, found {}", rows.len())));
+    }
+
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let blank = |x: usize| rows.iter().all(|row| row.get(x) != Some(&true));
+    let mut text = String::new();
+    let mut x = 0;
+    while x < width {
+        if blank(x) {
+            x += 1;
+            continue;
+        }
+        let start = x;
+        while x < width && !blank(x) {
+            x += 1;
+        }
+        let glyph = pattern(&rows, start..x);
+        match FONT.iter().find(|(_, font)| *font == glyph) {
+            Some((letter, _)) => text.push(*letter),
+            None => return Err(SolveError::ocr(format!("unknown glyph at column {}:\n{}", start + 1, glyph)))
+        }
+    }
+    if text.is_empty() {
+        return Err(SolveError::ocr("no letters found"));
+    }
+    Ok(text)
+}
+
+// Answer of a part rendering text: its letters, or the rendering itself when `raw`
+pub fn answer (art: String, raw: bool) -> Result<Answer, SolveError> {
+    if raw {
+        Ok(art)
+    } else {
+        read(&art)
+    }
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use crate::error::Kind;
+    use crate::params::Params;
+
+    #[test]
+    fn ocr_read () {
+        let art = "\
+#  #  ##  #  # ####  ##
+# #  #  # #  #    # #  #
+##   #  # #  #   #  #  #
+# #  #### #  #  #   ####
+# #  #  # #  # #    #  #
+#  # #  #  ##  #### #  #
+";
+        assert_eq!(super::read(art).unwrap(), "KAUZA");
+
+        // Leading blank column and a 5 columns wide Y
+        let art = " #   # ###\n #   #  # \n  # #   # \n   #    # \n   #    # \n   #   ###\n";
+        assert_eq!(super::read(art).unwrap(), "YI");
+    }
+
+    #[test]
+    fn ocr_errors () {
+        let err = super::read("##\n##\n##\n##\n##\n##").unwrap_err();
+        assert_eq!(err.kind, Kind::Ocr);
+        assert_eq!(err.to_string(), "unreadable text: unknown glyph at column 1:\n##\n##\n##\n##\n##\n##");
+        assert!(super::read("#\n#").is_err());
+        assert!(super::read("\n\n\n\n\n\n").is_err());
+    }
+
+    #[test]
+    fn ocr_raw () {
+        let art = String::from("#\n#");
+        assert_eq!(super::answer(art.clone(), true).unwrap(), art);
+        assert!(super::answer(art, false).is_err());
+
+        let params = Params::resolve(&[super::RAW], &[(String::from("raw"), 1)]);
+        assert_eq!(super::raw(&params), Ok(true));
+        assert_eq!(super::raw(&Params::defaults(&[super::RAW])), Ok(false));
+    }
+}
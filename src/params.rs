@@ -0,0 +1,116 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use crate::days::Solution;
+use crate::error::SolveError;
+
+// Constant of a solution that can be changed with `--param name=value`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Param {
+    pub name: &'static str,
+    pub default: i64,
+    pub description: &'static str
+}
+
+// `name=value` given on the command line
+pub type Override = (String, i64);
+
+// Values of the parameters of one solution, defaults replaced by the overrides
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Params {
+    values: HashMap<&'static str, i64>
+}
+impl Params {
+    // Overrides of parameters the solution doesn't declare are for other days, and ignored
+    pub fn resolve (declared: &[Param], overrides: &[Override]) -> Self {
+        let values = declared
+            .iter()
+            .map(|param| {
+                let value = overrides
+                    .iter()
+                    .rev()
+                    .find(|(name, _)| name == param.name)
+                    .map_or(param.default, |(_, value)| *value);
+                (param.name, value)
+            })
+            .collect();
+        Self { values }
+    }
+
+    pub fn defaults (declared: &[Param]) -> Self {
+        Self::resolve(declared, &[])
+    }
+
+    // `name=value` pairs sorted by name, e.g. to tell runs with different parameters apart
+    pub fn describe (&self) -> String {
+        let mut values: Vec<String> = self.values.iter().map(|(name, value)| format!("{}={}", name, value)).collect();
+        values.sort();
+        values.join(",")
+    }
+
+    // Value converted to the type the solution uses, e.g. a negative width is an error
+    pub fn get<T: TryFrom<i64>> (&self, name: &str) -> Result<T, SolveError> {
+        let value = *self.values
+            .get(name)
+            .ok_or_else(|| SolveError::unsolvable(format!("unknown parameter {}", name)))?;
+        T::try_from(value).map_err(|_| SolveError::unsolvable(format!("parameter {} is out of range ({})", name, value)))
+    }
+}
+
+// Parse `name=value`, underscores may separate digits
+pub fn parse (spec: &str) -> Result<Override, String> {
+    let (name, value) = spec
+        .split_once('=')
+        .ok_or(format!("Invalid parameter {:?} (name=value)", spec))?;
+    let value = value
+        .replace('_', "")
+        .parse()
+        .map_err(|_| format!("Invalid value {:?} for parameter {}", value, name))?;
+    Ok((name.to_string(), value))
+}
+
+// Every override has to be declared by one of the selected days
+pub fn check (solutions: &[&'static dyn Solution], overrides: &[Override]) -> Result<(), String> {
+    for (name, _) in overrides {
+        if !solutions.iter().any(|solution| solution.params().iter().any(|param| param.name == name)) {
+            return Err(format!("Unknown parameter {} (see `list`)", name));
+        }
+    }
+    Ok(())
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use super::Param;
+    use crate::days;
+
+    const PARAMS: &[Param] = &[
+        Param { name: "width", default: 25, description: "Image width" },
+        Param { name: "target", default: -1, description: "Expected output" }
+    ];
+
+    #[test]
+    fn params_resolve () {
+        let params = super::Params::defaults(PARAMS);
+        assert_eq!(params.get::<usize>("width"), Ok(25));
+        assert!(params.get::<usize>("target").is_err());
+        assert_eq!(params.get::<i64>("target"), Ok(-1));
+        assert!(params.get::<i64>("height").is_err());
+
+        let overrides = vec![(String::from("width"), 3), (String::from("steps"), 10), (String::from("width"), 4)];
+        assert_eq!(super::Params::resolve(PARAMS, &overrides).get::<u8>("width"), Ok(4));
+        assert_eq!(super::Params::resolve(PARAMS, &overrides).describe(), "target=-1,width=4");
+    }
+
+    #[test]
+    fn params_parse () {
+        assert_eq!(super::parse("width=3"), Ok((String::from("width"), 3)));
+        assert_eq!(super::parse("ore=1_000_000"), Ok((String::from("ore"), 1000000)));
+        assert!(super::parse("width").is_err());
+        assert!(super::parse("width=wide").is_err());
+
+        let day8 = vec![days::find("day8").unwrap()];
+        assert!(super::check(&day8, &[(String::from("height"), 2)]).is_ok());
+        assert!(super::check(&day8, &[(String::from("steps"), 2)]).is_err());
+    }
+}
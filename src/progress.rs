@@ -0,0 +1,123 @@
+use std::time::Duration;
+use crate::days;
+use crate::runner::{Run,Status};
+
+// The generated table replaces everything between these lines of the README
+pub const START: &str = "<!-- progress:start -->";
+pub const END: &str = "<!-- progress:end -->";
+
+pub const PUZZLE_URL: &str = "https://adventofcode.com";
+pub const SOURCE_URL: &str = "http://github.com/baspar/adventofcode2019/tree/master/src/days";
+
+// Links of a day by its name, `dayN` or `YYYY/dayN`
+fn id (day: &str) -> (u16, u8) {
+    days::parse_id(day).unwrap_or((days::DEFAULT_YEAR, 0))
+}
+
+pub fn puzzle_url (day: &str) -> String {
+    let (year, number) = id(day);
+    format!("{}/{}/day/{}", PUZZLE_URL, year, number)
+}
+
+pub fn source_url (day: &str) -> String {
+    let (year, number) = id(day);
+    format!("{}/y{}/day{}.rs", SOURCE_URL, year, number)
+}
+
+// Helper
+// Runs of one day: a star per verified part, and the time of both parts
+fn cells (day: &str, runs: &[Run]) -> (String, String) {
+    let runs: Vec<&Run> = runs.iter().filter(|run| run.name == day).collect();
+    let stars = "⭐".repeat(runs.iter().filter(|run| run.status == Status::Ok).count());
+    let time = if runs.iter().any(|run| matches!(run.status, Status::Timeout(_))) {
+        let timeout = runs.iter().map(|run| run.duration).max().unwrap_or_default();
+        format!("> {:.0?}", timeout)
+    } else if runs.iter().any(|run| run.status == Status::Ok) {
+        let total: Duration = runs.iter().filter(|run| run.status == Status::Ok).map(|run| run.duration).sum();
+        format!("{:.2?}", total)
+    } else {
+        String::new()
+    };
+    (format!("<a href=\"{}\">{}</a>", source_url(day), stars), time)
+}
+
+// Markdown tables of the given days (two halves), runs being verified against the stored answers
+pub fn table (days: &[String], runs: &[Run]) -> String {
+    let half = days.len().div_ceil(2);
+    days.chunks(half.max(1))
+        .map(|days| {
+            let (stars, times): (Vec<String>, Vec<String>) = days.iter().map(|day| cells(day, runs)).unzip();
+            let header: Vec<String> = days
+                .iter()
+                .map(|day| format!("<a href=\"{}\">{}</a>", puzzle_url(day), id(day).1))
+                .collect();
+            format!(
+                "|           | {} |\n| --------- |{}\n| Rust      | {} |\n| Time      | {} |\n",
+                header.join(" | "),
+                " --- |".repeat(days.len()),
+                stars.join(" | "),
+                times.join(" | ")
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+// Replace the table between the markers of `readme`
+pub fn update (readme: &str, table: &str) -> Result<String, String> {
+    let missing = || format!("No {} ... {} section in the README", START, END);
+    let start = readme.find(START).ok_or_else(missing)? + START.len();
+    let end = start + readme[start..].find(END).ok_or_else(missing)?;
+    Ok(format!("{}\n\n{}\n{}", &readme[..start], table, &readme[end..]))
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use crate::runner::{Part,Run,Status};
+
+    fn run (day: &str, part: Part, status: Status, millis: u64) -> Run {
+        Run {
+            name: String::from(day),
+            part,
+            answer: String::new(),
+            duration: Duration::from_millis(millis),
+            status,
+            memory: None,
+            cpu: None,
+            cached: false
+        }
+    }
+
+    #[test]
+    fn progress_table () {
+        let days: Vec<String> = (1..=3).map(|day| format!("day{}", day)).collect();
+        let runs = vec![
+            run("day1", Part::One, Status::Ok, 1),
+            run("day1", Part::Two, Status::Ok, 2),
+            run("day2", Part::One, Status::Ok, 5),
+            run("day2", Part::Two, Status::Wrong(String::from("42")), 1),
+            run("day3", Part::One, Status::Timeout(Duration::from_secs(10)), 10000),
+            run("day3", Part::Two, Status::Unimplemented, 0)
+        ];
+        let table = super::table(&days, &runs);
+        let lines: Vec<&str> = table.lines().collect();
+        assert_eq!(lines.len(), 9);
+        assert!(lines[0].contains("<a href=\"https://adventofcode.com/2019/day/1\">1</a> | <a href=\"https://adventofcode.com/2019/day/2\">2</a> |"));
+        assert_eq!(lines[1], "| --------- | --- | --- |");
+        assert!(lines[2].contains("/y2019/day1.rs\">⭐⭐</a> | <a href=\"http://github.com/baspar/adventofcode2019/tree/master/src/days/y2019/day2.rs\">⭐</a> |"));
+        assert_eq!(lines[3], "| Time      | 3.00ms | 5.00ms |");
+        assert!(lines[7].contains("/day3.rs\"></a> |"));
+        assert_eq!(lines[8], "| Time      | > 10s |");
+        assert_eq!(super::puzzle_url("2020/day7"), "https://adventofcode.com/2020/day/7");
+        assert_eq!(super::source_url("2020/day7"), "http://github.com/baspar/adventofcode2019/tree/master/src/days/y2020/day7.rs");
+    }
+
+    #[test]
+    fn progress_update () {
+        let readme = format!("# Title\n{}\nold\n{}\nrest\n", super::START, super::END);
+        assert_eq!(super::update(&readme, "new\n").unwrap(), format!("# Title\n{}\n\nnew\n\n{}\nrest\n", super::START, super::END));
+        assert!(super::update("# Title\n", "new\n").is_err());
+    }
+}
@@ -0,0 +1,169 @@
+use std::time::Duration;
+use crate::bench::Stats;
+use crate::days::Solution;
+use crate::progress;
+use crate::runner::{Part,Run,Status};
+
+// Everything is inline so that the file can be opened offline
+const STYLE: &str = "
+body { font-family: sans-serif; margin: 2em auto; max-width: 70em; color: #222; }
+table { border-collapse: collapse; margin-bottom: 2em; }
+th, td { border: 1px solid #ccc; padding: 0.3em 0.6em; text-align: left; vertical-align: top; }
+td.time { text-align: right; white-space: nowrap; }
+.ok { background: #e6f4e6; }
+.wrong, .failed, .timeout { background: #fbe3e3; }
+.unverified { background: #fdf6dc; }
+.unimplemented { color: #999; }
+pre { margin: 0; font-size: 0.8em; line-height: 1; }
+";
+
+pub fn escape (text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+// Helper
+fn answer (run: &Run) -> String {
+    let text = match &run.status {
+        Status::Failed(err) => err.clone(),
+        Status::Wrong(expected) => format!("{}\n(expected {})", run.answer.trim_end(), expected.trim_end()),
+        Status::Timeout(timeout) => format!("gave up after {:.2?}", timeout),
+        Status::Unimplemented => String::from("-"),
+        _ => run.answer.trim_end().to_string()
+    };
+    format!("<pre>{}</pre>", escape(&text))
+}
+
+fn cells (run: Option<&Run>) -> String {
+    match run {
+        Some(run) => format!(
+            "<td class=\"{}\">{}</td><td class=\"time\">{}</td>",
+            run.status,
+            answer(run),
+            if run.status == Status::Unimplemented { String::new() } else { format!("{:.2?}", run.duration) }
+        ),
+        None => String::from("<td></td><td></td>")
+    }
+}
+
+// Single HTML page: answers and timings of `runs`, the visuals of the days drawing one and the saved benchmarks
+pub fn html (solutions: &[&'static dyn Solution], runs: &[Run], visuals: &[(String, String)], history: &[(String, Vec<Stats>)]) -> String {
+    let find = |name: &str, part: Part| runs.iter().find(|run| run.name == name && run.part == part);
+    let solved = runs.iter().filter(|run| run.status == Status::Ok).count();
+    let total: Duration = runs.iter().map(|run| run.duration).sum();
+    // Years of the days in the report, e.g. `Advent of Code 2019, 2020`
+    let mut years: Vec<u16> = solutions.iter().map(|solution| solution.year()).collect();
+    years.sort_unstable();
+    years.dedup();
+    let title = format!("Advent of Code {}", years.iter().map(u16::to_string).collect::<Vec<String>>().join(", "));
+
+    let mut out = format!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{}</title>\n<style>{}</style>\n</head>\n<body>\n<h1>{}</h1>\n<p>{} of {} parts ok, in {:.2?}</p>\n",
+        title, STYLE, title, solved, runs.len(), total
+    );
+
+    out += "<h2>Answers</h2>\n<table>\n<tr><th>Day</th><th>Title</th><th>Part 1</th><th>Time</th><th>Part 2</th><th>Time</th></tr>\n";
+    for solution in solutions {
+        let name = solution.name();
+        out += &format!(
+            "<tr><td><a href=\"{}\">{}</a></td><td><a href=\"{}\">{}</a></td>{}{}</tr>\n",
+            progress::source_url(&name), name, progress::puzzle_url(&name), escape(solution.title()),
+            cells(find(&name, Part::One)),
+            cells(find(&name, Part::Two))
+        );
+    }
+    out += "</table>\n";
+
+    if !visuals.is_empty() {
+        out += "<h2>Visuals</h2>\n";
+        for (name, visual) in visuals {
+            out += &format!("<h3 id=\"{}\">{}</h3>\n<pre>{}</pre>\n", name, name, escape(visual.trim_end()));
+        }
+    }
+
+    // Median of each part in every saved benchmark
+    if !history.is_empty() {
+        out += "<h2>Benchmark history</h2>\n<table>\n<tr><th>Day</th><th>Part</th>";
+        for (name, _) in history {
+            out += &format!("<th>{}</th>", escape(name));
+        }
+        out += "</tr>\n";
+        for solution in solutions {
+            for part in &[Part::One, Part::Two] {
+                let medians: Vec<Option<Duration>> = history
+                    .iter()
+                    .map(|(_, stats)| stats.iter().find(|s| s.name == solution.name() && s.part == *part).map(|s| s.median))
+                    .collect();
+                if medians.iter().all(Option::is_none) {
+                    continue;
+                }
+                out += &format!("<tr><td>{}</td><td>{}</td>", solution.name(), part);
+                for median in medians {
+                    out += &format!("<td class=\"time\">{}</td>", median.map_or(String::from("-"), |median| format!("{:.2?}", median)));
+                }
+                out += "</tr>\n";
+            }
+        }
+        out += "</table>\n";
+    }
+
+    out += "</body>\n</html>\n";
+    out
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+    use crate::bench;
+    use crate::days;
+    use crate::error::SolveError;
+    use crate::params::Params;
+    use crate::runner::{Part,Run,Status};
+
+    #[test]
+    fn report_html () {
+        let solutions = vec![days::find("day1").unwrap(), days::find("day2").unwrap()];
+        let run = |name: &str, part, status, answer: &str| Run {
+            name: String::from(name),
+            part,
+            answer: String::from(answer),
+            duration: Duration::from_millis(2),
+            status,
+            memory: None,
+            cpu: None,
+            cached: false
+        };
+        let runs = vec![
+            run("day1", Part::One, Status::Ok, "3335787"),
+            run("day1", Part::Two, Status::Wrong(String::from("42")), "<41>"),
+            run("day2", Part::One, Status::Failed(String::from("boom")), "")
+        ];
+        let visuals = vec![(String::from("day8"), String::from("# #\n # \n"))];
+        let history = vec![(String::from("2019-12-01"), vec![bench::statistics(String::from("day1"), Part::One, vec![Duration::from_millis(3)])])];
+
+        let html = super::html(&solutions, &runs, &visuals, &history);
+        assert!(html.starts_with("<!DOCTYPE html>"));
+        assert!(html.contains("<p>1 of 3 parts ok, in 6.00ms</p>"));
+        assert!(html.contains("<a href=\"http://github.com/baspar/adventofcode2019/tree/master/src/days/y2019/day1.rs\">day1</a>"));
+        assert!(html.contains("<td class=\"ok\"><pre>3335787</pre></td><td class=\"time\">2.00ms</td>"));
+        assert!(html.contains("<td class=\"wrong\"><pre>&lt;41&gt;\n(expected 42)</pre></td>"));
+        assert!(html.contains("<td class=\"failed\"><pre>boom</pre></td>"));
+        assert!(html.contains("<h3 id=\"day8\">day8</h3>\n<pre># #\n #</pre>"));
+        assert!(html.contains("<th>2019-12-01</th></tr>\n<tr><td>day1</td><td>1</td><td class=\"time\">3.00ms</td></tr>"));
+        assert!(!html.contains("<script") && !html.contains("<link"));
+        assert!(html.contains("<title>Advent of Code 2019</title>") && html.contains("<h1>Advent of Code 2019</h1>"));
+
+        // Days of several years
+        let solutions = vec![days::find("day1").unwrap(), &Later, days::find("day2").unwrap()];
+        assert!(super::html(&solutions, &runs, &[], &[]).contains("<h1>Advent of Code 2019, 2020</h1>"));
+    }
+
+    struct Later;
+    impl days::Solution for Later {
+        fn year (&self) -> u16 { 2020 }
+        fn day (&self) -> u8 { 1 }
+        fn title (&self) -> &'static str { "Report Repair" }
+        fn part1 (&self, _input: &str, _params: &Params) -> Result<String, SolveError> { Ok(String::new()) }
+        fn part2 (&self, _input: &str, _params: &Params) -> Result<String, SolveError> { Ok(String::new()) }
+    }
+}
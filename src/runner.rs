@@ -0,0 +1,670 @@
+use std::cell::Cell;
+use std::fmt;
+use std::fs;
+use std::io::{self,Read};
+use std::panic::{self,AssertUnwindSafe};
+use std::sync::{Arc,mpsc};
+use std::sync::atomic::{AtomicUsize,Ordering};
+use std::thread;
+use std::time::{Duration,Instant};
+use crate::days::{self,Solution};
+use crate::cache::{self,Cache};
+use crate::memory;
+use crate::params::{Override,Params};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Part {
+    One,
+    Two
+}
+impl fmt::Display for Part {
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2")
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Status {
+    Ok,
+    Failed(String),
+    Unimplemented,
+    // Only set when verifying against stored answers
+    Wrong(String),
+    Unverified,
+    // Still running after the `--timeout`
+    Timeout(Duration)
+}
+impl fmt::Display for Status {
+    fn fmt (&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Status::Ok => f.pad("ok"),
+            Status::Failed(_) => f.pad("failed"),
+            Status::Unimplemented => f.pad("unimplemented"),
+            Status::Wrong(_) => f.pad("wrong"),
+            Status::Unverified => f.pad("unverified"),
+            Status::Timeout(_) => f.pad("timeout")
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub name: String,
+    pub part: Part,
+    pub answer: String,
+    pub duration: Duration,
+    pub status: Status,
+    // Peak bytes allocated while running, when asked for
+    pub memory: Option<usize>,
+    // CPU time of the thread running the part, when it ran to the end on a platform measuring it
+    pub cpu: Option<Duration>,
+    // Answer (and duration) taken from the cache instead of running the part
+    pub cached: bool
+}
+impl Run {
+    fn new (solution: &dyn Solution, part: Part, status: Status) -> Self {
+        Self {
+            name: solution.name(),
+            part,
+            answer: String::new(),
+            duration: Duration::default(),
+            status,
+            memory: None,
+            cpu: None,
+            cached: false
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Limits {
+    pub timeout: Option<Duration>,
+    pub memory: bool
+}
+
+// Selection
+// `all`, a year (`2019`), a day (`dayN` or `YYYY/dayN`) or an inclusive range `dayA..dayB`
+pub fn select (spec: &str) -> Result<Vec<&'static dyn Solution>, String> {
+    let solutions = days::all();
+    if spec == "all" {
+        return Ok(solutions);
+    }
+    if let Ok(year) = spec.parse::<u16>() {
+        let solutions: Vec<&'static dyn Solution> = solutions.into_iter().filter(|solution| solution.year() == year).collect();
+        return if solutions.is_empty() { Err(format!("Unknown year {} (see `list`)", year)) } else { Ok(solutions) };
+    }
+
+    let day = |id: &str| days::find(id)
+        .map(|solution| (solution.year(), solution.day()))
+        .ok_or(format!("Unknown day {:?} (see `list`)", id));
+    let (from, to) = match spec.split_once("..") {
+        // `2019/day1..day5`: the end is in the year of the start unless it names one
+        Some((from, to)) => {
+            let from = day(from)?;
+            let to = if to.contains('/') { day(to)? } else { day(&format!("{}/{}", from.0, to))? };
+            (from, to)
+        },
+        None => (day(spec)?, day(spec)?)
+    };
+    if from > to {
+        return Err(format!("Empty range {:?}", spec));
+    }
+
+    Ok(solutions
+        .into_iter()
+        .filter(|solution| from <= (solution.year(), solution.day()) && (solution.year(), solution.day()) <= to)
+        .collect())
+}
+
+// Input
+// Files of a day: its input is `./inputs/YYYY/dayN.txt`, its examples are under `./inputs/YYYY/dayN/`
+pub fn inputs (solution: &dyn Solution) -> String {
+    format!("./inputs/{}/day{}", solution.year(), solution.day())
+}
+
+// The same text whatever saved it: no BOM, `\n` line endings, no whitespace at the end of lines nor blank lines at the end
+pub fn normalize (input: &str) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+    input
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<&str>>()
+        .join("\n")
+        .trim_end_matches('\n')
+        .to_string()
+}
+
+// Where a day's input comes from, only the default one is read by default
+#[derive(Debug, Clone, PartialEq)]
+pub enum Source {
+    Default,
+    Inline(String),
+    File(String),
+    Stdin,
+    // `./inputs/YYYY/dayN/NAME.txt`
+    Example(String)
+}
+impl Source {
+    pub fn describe (&self, solution: &dyn Solution) -> String {
+        match self {
+            Source::Default => format!("{}.txt", inputs(solution)),
+            Source::Inline(_) => String::from("(inline)"),
+            Source::File(path) => path.clone(),
+            Source::Stdin => String::from("(stdin)"),
+            Source::Example(name) => format!("{}/{}.txt", inputs(solution), name)
+        }
+    }
+
+    // Whatever the source, days get their input normalized unless they opt out
+    pub fn read (&self, solution: &dyn Solution) -> Result<String, String> {
+        let file = |filename: String| fs::read_to_string(&filename)
+            .map_err(|err| format!("cannot read {}: {}", filename, err));
+        let input = match self {
+            Source::Inline(input) => input.clone(),
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin()
+                    .read_to_string(&mut input)
+                    .map_err(|err| format!("cannot read stdin: {}", err))?;
+                input
+            },
+            _ => file(self.describe(solution))?
+        };
+        Ok(if solution.normalized_input() { normalize(&input) } else { input })
+    }
+
+    // Sources that make sense for several days at once
+    pub fn per_day (&self) -> bool {
+        matches!(self, Source::Default | Source::Example(_))
+    }
+}
+
+
+// Run
+pub fn panic_message (payload: Box<dyn std::any::Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        String::from(*message)
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        String::from("panicked")
+    }
+}
+
+// Error of a part that panicked, named like the errors it returns
+pub fn panicked (solution: &dyn Solution, part: Part, message: &str) -> String {
+    format!("{} part {}: panicked: {}", solution.name(), part, message)
+}
+
+thread_local! {
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+}
+
+// Run `f`, turning a panic into its message. The (process wide) panic hook is left alone, so the
+// panic is still printed by the default hook: a binary can install one skipping them with `catching`
+pub fn catch<T, F: FnOnce() -> T> (f: F) -> Result<T, String> {
+    let outer = CATCHING.with(|catching| catching.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.with(|catching| catching.set(outer));
+    result.map_err(panic_message)
+}
+
+// Whether the current thread is running inside `catch`, whose caller reports the panics
+pub fn catching () -> bool {
+    CATCHING.with(Cell::get)
+}
+
+// CPU time used so far by the calling thread
+#[cfg(target_os = "linux")]
+fn thread_cpu_time () -> Option<Duration> {
+    use std::os::raw::{c_int,c_long};
+    #[repr(C)]
+    struct Timespec {
+        seconds: c_long,
+        nanoseconds: c_long
+    }
+    extern "C" {
+        fn clock_gettime (clock: c_int, time: *mut Timespec) -> c_int;
+    }
+    const CLOCK_THREAD_CPUTIME_ID: c_int = 3;
+    let mut time = Timespec { seconds: 0, nanoseconds: 0 };
+    // Only writes to `time`
+    match unsafe { clock_gettime(CLOCK_THREAD_CPUTIME_ID, &mut time) } {
+        0 => Some(Duration::new(time.seconds as u64, time.nanoseconds as u32)),
+        _ => None
+    }
+}
+#[cfg(not(target_os = "linux"))]
+fn thread_cpu_time () -> Option<Duration> {
+    None
+}
+
+pub fn run_part (solution: &dyn Solution, part: Part, input: &str, params: &Params) -> Run {
+    let (implemented1, implemented2) = solution.implemented();
+    let implemented = match part {
+        Part::One => implemented1,
+        Part::Two => implemented2
+    };
+    let mut run = Run::new(solution, part, Status::Unimplemented);
+    if !implemented {
+        return run;
+    }
+
+    let (start, cpu) = (Instant::now(), thread_cpu_time());
+    // Panics are still caught, for the bugs errors don't cover
+    let result = catch(|| match part {
+        Part::One => solution.part1(input, params),
+        Part::Two => solution.part2(input, params)
+    });
+    run.duration = start.elapsed();
+    run.cpu = cpu.zip(thread_cpu_time()).map(|(start, end)| end - start);
+    match result {
+        Ok(Ok(answer)) => {
+            run.answer = answer;
+            run.status = Status::Ok;
+        },
+        Ok(Err(err)) => run.status = Status::Failed(err.at(solution, part).to_string()),
+        Err(message) => run.status = Status::Failed(panicked(solution, part, &message))
+    }
+    run
+}
+
+// `run_part`, with the peak memory of the part when `memory` is set (and the counting allocator installed)
+fn run_measured (solution: &dyn Solution, part: Part, input: &str, params: &Params, memory: bool) -> Run {
+    if !memory {
+        return run_part(solution, part, input, params);
+    }
+    let baseline = memory::reset_peak();
+    let mut run = run_part(solution, part, input, params);
+    run.memory = memory::peak_since(baseline);
+    run
+}
+
+// Run a part on its own thread, giving up on it after `limits.timeout`
+// A part that times out can't be stopped: its thread is left running in the background
+pub fn run_limited (solution: &'static dyn Solution, part: Part, input: &Arc<String>, params: &Params, limits: Limits) -> Run {
+    match limits.timeout {
+        None => run_measured(solution, part, input, params, limits.memory),
+        Some(timeout) => {
+            let (sender, receiver) = mpsc::channel();
+            let (input, params) = (Arc::clone(input), params.clone());
+            // Nobody listens anymore once the part timed out
+            thread::spawn(move || { let _ = sender.send(run_measured(solution, part, &input, &params, limits.memory)); });
+            receiver
+                .recv_timeout(timeout)
+                .unwrap_or_else(|_| Run { duration: timeout, ..Run::new(solution, part, Status::Timeout(timeout)) })
+        }
+    }
+}
+
+// Run every part of the given days on `jobs` threads, panics are reported instead of aborting the run
+// `overrides` replace the defaults of the parameters each day declares
+// With a `cache`, parts solved before on the same input and source aren't run again, and new answers are added to it
+// `on_run` sees each run in order (day, then part) as soon as it and the previous ones are done, and may update its status
+pub fn run_all<F> (solutions: &[&'static dyn Solution], source: &Source, jobs: usize, limits: Limits, overrides: &[Override], mut cache: Option<&mut Cache>, mut on_run: F) -> Vec<Run>
+where F: FnMut(&mut Run) {
+    let mut tasks = Vec::new();
+    let mut keys = Vec::new();
+    let mut pending: Vec<Option<Run>> = Vec::new();
+    for solution in solutions {
+        let input = source.read(*solution).map(Arc::new);
+        let params = Params::resolve(solution.params(), overrides);
+        for part in &[Part::One, Part::Two] {
+            let key = input.as_ref().ok().map(|input| cache::key(*solution, *part, input, &params));
+            let cached = cache.as_ref().zip(key.as_ref()).and_then(|(cache, key)| cache.get(key, *solution, *part));
+            // Cached runs are already done, workers skip them
+            tasks.push((*solution, *part, input.clone(), params.clone(), cached.is_none()));
+            keys.push(key);
+            pending.push(cached);
+        }
+    }
+
+    // Workers pick the next task, the calling thread reports finished runs in order
+    let next_task = AtomicUsize::new(0);
+    let (sender, receiver) = mpsc::channel();
+    let mut runs = Vec::with_capacity(tasks.len());
+    let mut report = |pending: &mut Vec<Option<Run>>, runs: &mut Vec<Run>| {
+        while let Some(mut run) = pending.get_mut(runs.len()).and_then(Option::take) {
+            on_run(&mut run);
+            runs.push(run);
+        }
+    };
+    thread::scope(|scope| {
+        let remaining = tasks.iter().filter(|task| task.4).count();
+        for _ in 0..jobs.max(1).min(remaining) {
+            let sender = sender.clone();
+            let (tasks, next_task) = (&tasks, &next_task);
+            scope.spawn(move || loop {
+                let index = next_task.fetch_add(1, Ordering::SeqCst);
+                let (solution, part, input, params, to_run) = match tasks.get(index) {
+                    Some(task) => task,
+                    None => break
+                };
+                if !to_run {
+                    continue;
+                }
+                let run = match input {
+                    Ok(input) => run_limited(*solution, *part, input, params, limits),
+                    Err(err) => Run::new(*solution, *part, Status::Failed(err.clone()))
+                };
+                if sender.send((index, run)).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(sender);
+
+        report(&mut pending, &mut runs);
+        for (index, run) in receiver {
+            if let (Some(cache), Some(key)) = (cache.as_mut(), keys[index].take()) {
+                cache.insert(key, &run);
+            }
+            pending[index] = Some(run);
+            report(&mut pending, &mut runs);
+        }
+    });
+    runs
+}
+
+// Table
+// With `memory`, a column shows the peak memory of each part
+pub fn header (memory: bool) -> String {
+    let memory = if memory { format!(" {:>10}", "Memory") } else { String::new() };
+    format!("{:<6} {:<4} {:<13} {:>12}{}  {}", "Day", "Part", "Status", "Time", memory, "Answer")
+}
+
+pub fn row (run: &Run, memory: bool) -> String {
+    let time = match run.status {
+        Status::Unimplemented => String::from("-"),
+        _ if run.cached => String::from("cached"),
+        _ => format!("{:.2?}", run.duration)
+    };
+    let answer = match &run.status {
+        Status::Failed(err) => err.clone(),
+        Status::Wrong(expected) => format!("{} (expected {})", run.answer.trim_end(), expected.trim_end()),
+        Status::Timeout(timeout) => format!("gave up after {:.2?}", timeout),
+        _ => run.answer.trim_end().to_string()
+    };
+    let (memory, width) = match (memory, run.memory) {
+        (false, _) => (String::new(), 40),
+        (true, Some(bytes)) => (format!(" {:>10}", memory::format(bytes)), 51),
+        (true, None) => (format!(" {:>10}", "-"), 51)
+    };
+    let indent = format!("\n{:width$}", "", width = width);
+    format!("{:<6} {:<4} {:<13} {:>12}{}  {}", run.name, run.part, run.status, time, memory, answer.replace('\n', &indent))
+        .trim_end()
+        .to_string()
+}
+
+// `wall` is the elapsed time of the whole run, shorter than the CPU time of the parts when running in parallel
+pub fn totals (runs: &[Run], wall: Duration) -> String {
+    let count = |status: fn(&Status) -> bool| runs.iter().filter(|run| status(&run.status)).count();
+    // Cached parts didn't run, and the threads of timed out ones are still running
+    let cpu = match thread_cpu_time() {
+        Some(_) => format!(
+            "{:.2?} CPU{}",
+            runs.iter().filter_map(|run| run.cpu).sum::<Duration>(),
+            if runs.iter().any(|run| matches!(run.status, Status::Timeout(_))) { " (timed out parts left out)" } else { "" }
+        ),
+        None => String::from("unmeasured CPU")
+    };
+    format!(
+        "Total: {} parts, {} ok, {} failed, {} unimplemented{}{}{} in {}, {:.2?} wall",
+        runs.len(),
+        count(|status| *status == Status::Ok),
+        count(|status| matches!(status, Status::Failed(_))),
+        count(|status| *status == Status::Unimplemented),
+        match count(|status| matches!(status, Status::Timeout(_))) {
+            0 => String::new(),
+            n => format!(", {} timed out", n)
+        },
+        if runs.iter().any(|run| matches!(run.status, Status::Wrong(_) | Status::Unverified)) {
+            format!(
+                ", {} wrong, {} unverified",
+                count(|status| matches!(status, Status::Wrong(_))),
+                count(|status| *status == Status::Unverified)
+            )
+        } else {
+            String::new()
+        },
+        match runs.iter().filter(|run| run.cached).count() {
+            0 => String::new(),
+            n => format!(", {} cached", n)
+        },
+        cpu,
+        wall
+    )
+}
+
+// JSON
+pub fn json_string (value: &str) -> String {
+    let mut out = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => out += "\\\"",
+            '\\' => out += "\\\\",
+            '\n' => out += "\\n",
+            '\r' => out += "\\r",
+            '\t' => out += "\\t",
+            c if (c as u32) < 0x20 => out += &format!("\\u{:04x}", c as u32),
+            c => out.push(c)
+        }
+    }
+    out.push('"');
+    out
+}
+
+// One object per run, `error` and `expected` are null unless the part failed (or timed out) or is wrong
+pub fn json (run: &Run) -> String {
+    let answer = match run.status {
+        Status::Failed(_) | Status::Unimplemented | Status::Timeout(_) => String::from("null"),
+        _ => json_string(&run.answer)
+    };
+    let (error, expected) = match &run.status {
+        Status::Failed(err) => (json_string(err), String::from("null")),
+        Status::Timeout(timeout) => (json_string(&format!("gave up after {:.2?}", timeout)), String::from("null")),
+        Status::Wrong(expected) => (String::from("null"), json_string(expected)),
+        _ => (String::from("null"), String::from("null"))
+    };
+    format!(
+        "{{\"day\":{},\"part\":{},\"status\":{},\"answer\":{},\"duration_ns\":{},\"cached\":{},\"peak_memory_bytes\":{},\"error\":{},\"expected\":{}}}",
+        json_string(&run.name),
+        run.part,
+        json_string(&run.status.to_string()),
+        answer,
+        run.duration.as_nanos(),
+        run.cached,
+        run.memory.map_or(String::from("null"), |bytes| bytes.to_string()),
+        error,
+        expected
+    )
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use std::time::Duration;
+    use super::{Part,Status};
+    use crate::days;
+    use crate::params::Params;
+
+    #[test]
+    fn runner_select () {
+        let names = |spec| super::select(spec).unwrap().iter().map(|s| s.name()).collect::<Vec<_>>();
+        assert_eq!(names("all").len(), 25);
+        assert_eq!(names("day3"), vec!["day3"]);
+        assert_eq!(names("day9..day11"), vec!["day9", "day10", "day11"]);
+        assert!(super::select("day11..day9").is_err());
+        assert!(super::select("day26").is_err());
+        assert_eq!(names("2019/day9..day11"), vec!["day9", "day10", "day11"]);
+        assert_eq!(names("day24..2019/day25"), vec!["day24", "day25"]);
+        assert_eq!(super::select("2019").unwrap().len(), 25);
+        assert!(super::select("2018").is_err());
+        assert!(super::select("2018/day1").is_err());
+    }
+
+    #[test]
+    fn runner_run_part () {
+        let day1 = days::find("day1").unwrap();
+        let run = super::run_part(day1, Part::Two, "1969", &Params::default());
+        assert_eq!((run.status, run.answer.as_str()), (Status::Ok, "966"));
+
+        let run = super::run_part(day1, Part::One, "not a number", &Params::default());
+        assert!(matches!(run.status, Status::Failed(_)));
+
+        let day25 = days::find("day25").unwrap();
+        assert_eq!(super::run_part(day25, Part::One, "", &Params::default()).status, Status::Unimplemented);
+    }
+
+    #[test]
+    fn runner_catch () {
+        assert_eq!(super::catch(|| 42), Ok(42));
+        assert_eq!(super::catch(|| -> i32 { panic!("boom {}", 1) }), Err(String::from("boom 1")));
+        assert!(super::catch(super::catching).unwrap());
+        assert!(!super::catching());
+
+        let run = super::run_part(&Slow, Part::One, "not a number", &Params::default());
+        assert!(matches!(run.status, Status::Failed(message) if message.starts_with("2100/day99 part 1: panicked: ")));
+    }
+
+    #[test]
+    fn runner_run_all () {
+        let solutions = super::select("day1..day4").unwrap();
+        let source = super::Source::Inline(String::from("not an input"));
+        let mut seen = Vec::new();
+        let runs = super::run_all(&solutions, &source, 3, super::Limits::default(), &[], None, |run| seen.push((run.name.clone(), run.part)));
+        let expected: Vec<(String, Part)> = (1..=4)
+            .flat_map(|day| vec![(format!("day{}", day), Part::One), (format!("day{}", day), Part::Two)])
+            .collect();
+        assert_eq!(seen, expected);
+        assert_eq!(runs.len(), 8);
+        assert!(runs.iter().all(|run| matches!(run.status, Status::Failed(_))));
+
+        // Overrides only change the parameters of the days declaring them
+        let solutions = super::select("day8").unwrap();
+        let source = super::Source::Inline(String::from("123456789012"));
+        let overrides = vec![(String::from("width"), 3), (String::from("height"), 2), (String::from("steps"), 10)];
+        let runs = super::run_all(&solutions, &source, 1, super::Limits::default(), &overrides, None, |_| {});
+        assert_eq!((&runs[0].status, runs[0].answer.as_str()), (&Status::Ok, "1"));
+
+        // Only the parts missing from the cache run
+        let solutions = super::select("day1").unwrap();
+        let source = super::Source::Inline(String::from("1969"));
+        let mut cache = crate::cache::Cache::default();
+        let runs = super::run_all(&solutions, &source, 2, super::Limits::default(), &[], Some(&mut cache), |_| {});
+        assert!(runs.iter().all(|run| !run.cached));
+        let runs = super::run_all(&solutions, &source, 2, super::Limits::default(), &[], Some(&mut cache), |_| {});
+        assert!(runs.iter().all(|run| run.cached && run.status == Status::Ok));
+        assert_eq!(runs[1].answer, "966");
+        assert!(super::row(&runs[1], false).contains("cached"));
+    }
+
+    // Part 1 sleeps for the number of milliseconds given as input, in a year of its own
+    struct Slow;
+    impl days::Solution for Slow {
+        fn year (&self) -> u16 { 2100 }
+        fn normalized_input (&self) -> bool { false }
+        fn day (&self) -> u8 { 99 }
+        fn title (&self) -> &'static str { "Slow" }
+        fn part1 (&self, input: &str, _params: &Params) -> Result<String, crate::error::SolveError> {
+            let millis = input.parse().unwrap();
+            std::thread::sleep(Duration::from_millis(millis));
+            Ok(input.to_string())
+        }
+        fn part2 (&self, _input: &str, _params: &Params) -> Result<String, crate::error::SolveError> {
+            Ok(vec![0u8; 1 << 20].len().to_string())
+        }
+    }
+
+    #[test]
+    fn runner_limits () {
+        let limits = super::Limits { timeout: Some(Duration::from_millis(50)), memory: true };
+        let run = super::run_limited(&Slow, Part::One, &Arc::new(String::from("500")), &Params::default(), limits);
+        assert_eq!(run.status, Status::Timeout(Duration::from_millis(50)));
+        assert_eq!((run.name.as_str(), super::Source::Default.describe(&Slow).as_str()), ("2100/day99", "./inputs/2100/day99.txt"));
+        assert!(super::row(&run, false).contains("gave up after 50.00ms"));
+
+        let run = super::run_limited(&Slow, Part::One, &Arc::new(String::from("1")), &Params::default(), limits);
+        assert_eq!((run.status, run.answer.as_str()), (Status::Ok, "1"));
+        let run = super::run_limited(&Slow, Part::Two, &Arc::new(String::new()), &Params::default(), limits);
+        assert!(run.memory.unwrap() >= 1 << 20);
+        assert!(super::row(&run, true).contains("MiB"));
+
+        let run = super::run_limited(&Slow, Part::Two, &Arc::new(String::new()), &Params::default(), super::Limits::default());
+        assert_eq!(run.memory, None);
+    }
+
+    #[test]
+    fn runner_totals () {
+        let limits = super::Limits { timeout: Some(Duration::from_millis(50)), memory: false };
+        let runs = vec![
+            super::run_limited(&Slow, Part::One, &Arc::new(String::from("20")), &Params::default(), limits),
+            super::run_limited(&Slow, Part::One, &Arc::new(String::from("500")), &Params::default(), limits)
+        ];
+        // Sleeping doesn't use the CPU, and the timed out part isn't counted at all
+        assert!(runs[0].duration >= Duration::from_millis(20));
+        assert_eq!(runs[1].cpu, None);
+        let totals = super::totals(&runs, Duration::from_millis(60));
+        assert!(totals.contains("1 timed out"));
+        if cfg!(target_os = "linux") {
+            assert!(runs[0].cpu.unwrap() < Duration::from_millis(20));
+            assert!(totals.contains(" CPU (timed out parts left out), 60.00ms wall"), "{}", totals);
+        } else {
+            assert!(totals.contains("unmeasured CPU"));
+        }
+    }
+
+    #[test]
+    fn runner_source () {
+        let day3 = days::find("day3").unwrap();
+        assert_eq!(super::Source::Default.describe(day3), "./inputs/2019/day3.txt");
+        assert_eq!(super::Source::Example(String::from("small")).describe(day3), "./inputs/2019/day3/small.txt");
+        assert!(super::Source::Default.read(day3).is_ok());
+        assert_eq!(super::Source::Inline(String::from("R8,U5")).read(day3).unwrap(), "R8,U5");
+        assert!(super::Source::File(String::from("./inputs/missing.txt")).read(day3).is_err());
+        assert!(!super::Source::Stdin.per_day());
+    }
+
+    #[test]
+    fn runner_normalize () {
+        assert_eq!(super::normalize("\u{feff}R8,U5 \r\nU7,R6\t\r\n\r\n\n"), "R8,U5\nU7,R6");
+        assert_eq!(super::normalize("  .#\n\n##"), "  .#\n\n##");
+        assert_eq!(super::normalize("\n \n"), "");
+
+        // A CRLF map reads like the LF one
+        let day10 = days::find("day10").unwrap();
+        let input = super::Source::Inline(String::from(".#\r\n##\r\n")).read(day10).unwrap();
+        assert_eq!(input, ".#\n##");
+        assert_eq!(super::run_part(day10, Part::One, &input, &Params::default()).status, Status::Ok);
+
+        // Days opting out get their input as is
+        assert_eq!(super::Source::Inline(String::from(" 1 \r\n")).read(&Slow).unwrap(), " 1 \r\n");
+        let day20 = days::find("day20").unwrap();
+        assert_eq!(super::Source::Inline(String::from("  A  \n  .  \n")).read(day20).unwrap(), "  A  \n  .  \n");
+    }
+
+    #[test]
+    fn runner_json () {
+        assert_eq!(super::json_string("a\"b\\c\n\u{1}"), "\"a\\\"b\\\\c\\n\\u0001\"");
+
+        let day1 = days::find("day1").unwrap();
+        let mut run = super::run_part(day1, Part::Two, "1969", &Params::default());
+        run.duration = std::time::Duration::from_nanos(1500);
+        run.memory = Some(2048);
+        assert_eq!(
+            super::json(&run),
+            "{\"day\":\"day1\",\"part\":2,\"status\":\"ok\",\"answer\":\"966\",\"duration_ns\":1500,\"cached\":false,\"peak_memory_bytes\":2048,\"error\":null,\"expected\":null}"
+        );
+
+        run.status = Status::Failed(String::from("boom"));
+        run.memory = None;
+        assert!(super::json(&run).contains("\"answer\":null,\"duration_ns\":1500,\"cached\":false,\"peak_memory_bytes\":null,\"error\":\"boom\""));
+
+        run.status = Status::Timeout(std::time::Duration::from_secs(2));
+        assert!(super::json(&run).contains("\"answer\":null,\"duration_ns\":1500,\"cached\":false,\"peak_memory_bytes\":null,\"error\":\"gave up after 2.00s\""));
+    }
+}
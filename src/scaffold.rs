@@ -0,0 +1,228 @@
+use std::fs;
+use std::path::Path;
+use crate::days;
+
+// Module of a day not solved yet, skipped by the runner until `implemented` is removed
+pub fn template (year: u16, day: u8, title: &str) -> String {
+    format!(
+r#"use crate::error::{{Answer,SolveError}};
+use super::{{Params,Solution}};
+
+// Helper
+fn read_input (_input: &str) -> i32 {{
+    0
+}}
+
+// Part1
+pub fn part1 (input: &str) -> Result<Answer, SolveError> {{
+    Ok(format!("{{}}", read_input(input)))
+}}
+
+// Part2
+pub fn part2 (input: &str) -> Result<Answer, SolveError> {{
+    Ok(format!("{{}}", read_input(input)))
+}}
+
+// Solution
+pub struct Day;
+impl Solution for Day {{
+    fn year (&self) -> u16 {{ {year} }}
+    fn day (&self) -> u8 {{ {day} }}
+    fn title (&self) -> &'static str {{ {title:?} }}
+    fn part1 (&self, input: &str, _params: &Params) -> Result<Answer, SolveError> {{ part1(input) }}
+    fn part2 (&self, input: &str, _params: &Params) -> Result<Answer, SolveError> {{ part2(input) }}
+    fn implemented (&self) -> (bool, bool) {{ (false, false) }}
+}}
+
+// Tests
+#[cfg(test)]
+mod tests {{
+    #[test]
+    fn day{day}_part1 () {{
+        assert_eq!(super::part1("0").unwrap(), "0");
+    }}
+
+    #[test]
+    fn day{day}_part2 () {{
+        assert_eq!(super::part2("0").unwrap(), "0");
+    }}
+}}
+"#,
+        year = year,
+        day = day,
+        title = title
+    )
+}
+
+// Helper
+// `dayN` or `N`, for a day of the calendar
+pub fn parse_day (name: &str) -> Result<u8, String> {
+    name.trim_start_matches("day")
+        .parse()
+        .ok()
+        .filter(|day| (1..=25).contains(day))
+        .ok_or(format!("Invalid day {:?} (day1 to day25)", name))
+}
+
+// `dayN`, `N` or `YYYY/dayN`, the year defaulting to the one of days named without it
+pub fn parse_id (name: &str) -> Result<(u16, u8), String> {
+    match name.split_once('/') {
+        Some((year, day)) => Ok((year.parse().map_err(|_| format!("Invalid year {:?}", year))?, parse_day(day)?)),
+        None => Ok((days::DEFAULT_YEAR, parse_day(name)?))
+    }
+}
+
+// A module is only replaced while it's still the untouched template, with the title it's registered with
+// (days this binary doesn't know yet can only match the `title` being written)
+fn is_placeholder (year: u16, day: u8, module: &str, title: &str) -> bool {
+    let registered = days::find(&format!("{}/day{}", year, day)).map(|solution| solution.title());
+    registered.into_iter().chain(Some(title)).any(|title| module == template(year, day, title))
+}
+
+// Add `dayN` to the list of `year` in the `register!` of `days.rs`, keeping it sorted, returns whether it changed
+// A year not registered yet gets a list of its own
+fn register (days: &str, year: u16, day: u8) -> Result<Option<String>, String> {
+    let missing = || String::from("No register!(...) list found");
+    let start = days.find("register!(\n").ok_or_else(missing)? + "register!(\n".len();
+    let end = start + days[start..].find(");").ok_or_else(missing)?;
+    let header = format!("    y{} {{\n", year);
+    let (start, end, new_year) = match days[start..end].find(&header) {
+        Some(offset) => {
+            let start = start + offset + header.len();
+            (start, start + days[start..].find("    },").ok_or_else(missing)?, false)
+        },
+        None => (end, end, true)
+    };
+    let mut numbers: Vec<u8> = days[start..end]
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|name| !name.is_empty())
+        .map(parse_day)
+        .collect::<Result<_, _>>()?;
+    if numbers.contains(&day) {
+        return Ok(None);
+    }
+    numbers.push(day);
+    numbers.sort_unstable();
+
+    // Ten days per line
+    let list: String = numbers
+        .chunks(10)
+        .map(|chunk| format!("        {},\n", chunk.iter().map(|day| format!("day{}", day)).collect::<Vec<String>>().join(", ")))
+        .collect();
+    let list = if new_year { format!("{}{}    }},\n", header, list) } else { list };
+    Ok(Some(format!("{}{}{}", &days[..start], list, &days[end..])))
+}
+
+// Generate the module, input and example of `day` under `root`, returns what was written
+// Solved days are never overwritten, existing inputs are kept
+pub fn create (root: &Path, year: u16, day: u8, title: &str) -> Result<Vec<String>, String> {
+    let write = |path: &Path, text: &str| fs::write(path, text).map_err(|err| format!("{}: {}", path.display(), err));
+    let mut written = Vec::new();
+
+    let module_path = root.join(format!("src/days/y{}/day{}.rs", year, day));
+    let module = template(year, day, title);
+    match fs::read_to_string(&module_path) {
+        Ok(existing) if existing == module => {},
+        Ok(existing) if !is_placeholder(year, day, &existing, title) => {
+            return Err(format!("{} already holds a solution, not overwriting it", module_path.display()));
+        },
+        _ => {
+            if let Some(parent) = module_path.parent() {
+                fs::create_dir_all(parent).map_err(|err| format!("{}: {}", parent.display(), err))?;
+            }
+            write(&module_path, &module)?;
+            written.push(module_path.display().to_string());
+        }
+    }
+
+    let days_path = root.join("src/days.rs");
+    let days = fs::read_to_string(&days_path).map_err(|err| format!("{}: {}", days_path.display(), err))?;
+    if let Some(days) = register(&days, year, day)? {
+        write(&days_path, &days)?;
+        written.push(days_path.display().to_string());
+    }
+
+    for path in &[root.join(format!("inputs/{}/day{}.txt", year, day)), root.join(format!("inputs/{}/day{}/example1.txt", year, day))] {
+        if !path.exists() {
+            if let Some(parent) = path.parent() {
+                fs::create_dir_all(parent).map_err(|err| format!("{}: {}", parent.display(), err))?;
+            }
+            write(path, "")?;
+            written.push(path.display().to_string());
+        }
+    }
+    Ok(written)
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use crate::days;
+
+    #[test]
+    fn scaffold_template () {
+        // The placeholders still to solve are generated from the template, day 20 opting out of the normalization
+        for day in (19..=25).filter(|day| *day != 20) {
+            let module = fs::read_to_string(format!("./src/days/y2019/day{}.rs", day)).unwrap();
+            assert!(super::is_placeholder(2019, day, &module, ""), "day{} differs from the template", day);
+        }
+        for day in &[1, 20] {
+            let module = fs::read_to_string(format!("./src/days/y2019/day{}.rs", day)).unwrap();
+            assert!(!super::is_placeholder(2019, *day, &module, ""));
+        }
+
+        // Titles with quotes or escapes are matched as registered
+        let quoted = super::template(2019, 19, "The \"Tractor\" \\ Beam");
+        assert!(!super::is_placeholder(2019, 19, &quoted, ""));
+        assert!(super::is_placeholder(2019, 19, &quoted, "The \"Tractor\" \\ Beam"));
+
+        assert_eq!(super::parse_day("day7"), Ok(7));
+        assert_eq!(super::parse_day("12"), Ok(12));
+        assert!(super::parse_day("day26").is_err());
+        assert_eq!(super::parse_id("day7"), Ok((2019, 7)));
+        assert_eq!(super::parse_id("2020/day1"), Ok((2020, 1)));
+        assert!(super::parse_id("next/day1").is_err());
+    }
+
+    #[test]
+    fn scaffold_register () {
+        let days = "register!(\n    y2019 {\n        day1, day2,\n    },\n);\n";
+        assert_eq!(super::register(days, 2019, 2), Ok(None));
+        assert_eq!(super::register(days, 2019, 11).unwrap().unwrap(), "register!(\n    y2019 {\n        day1, day2, day11,\n    },\n);\n");
+        assert_eq!(
+            super::register(days, 2020, 3).unwrap().unwrap(),
+            "register!(\n    y2019 {\n        day1, day2,\n    },\n    y2020 {\n        day3,\n    },\n);\n"
+        );
+        assert!(super::register("mod day1;", 2019, 3).is_err());
+    }
+
+    #[test]
+    fn scaffold_create () {
+        let root = std::env::temp_dir().join(format!("scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src/days")).unwrap();
+        fs::write(root.join("src/days.rs"), "register!(\n    y2019 {\n        day1,\n    },\n);\n").unwrap();
+
+        let written = super::create(&root, 2019, 2, "I Was Told There Would Be No Math").unwrap();
+        assert_eq!(written.len(), 4);
+        assert!(fs::read_to_string(root.join("src/days.rs")).unwrap().contains("day1, day2,"));
+        assert_eq!(fs::read_to_string(root.join("inputs/2019/day2/example1.txt")).unwrap(), "");
+
+        // Nothing left to do, then a solved day is kept
+        assert_eq!(super::create(&root, 2019, 2, "I Was Told There Would Be No Math").unwrap(), Vec::<String>::new());
+        fs::write(root.join("src/days/y2019/day2.rs"), "// Solved").unwrap();
+        assert!(super::create(&root, 2019, 2, "Another Title").is_err());
+        assert_eq!(fs::read_to_string(root.join("src/days/y2019/day2.rs")).unwrap(), "// Solved");
+
+        // A placeholder with the registered title can be retitled
+        fs::write(root.join("src/days/y2019/day20.rs"), super::template(2019, 20, days::find("day20").unwrap().title())).unwrap();
+        super::create(&root, 2019, 20, "Donut \"Maze\"").unwrap();
+        assert!(fs::read_to_string(root.join("src/days/y2019/day20.rs")).unwrap().contains("\"Donut \\\"Maze\\\"\""));
+
+        // Days of another year go to their own directories
+        super::create(&root, 2020, 1, "Report Repair").unwrap();
+        assert!(fs::read_to_string(root.join("src/days/y2020/day1.rs")).unwrap().contains("fn year (&self) -> u16 { 2020 }"));
+        assert!(root.join("inputs/2020/day1.txt").exists());
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,91 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::SystemTime;
+use crate::days::Solution;
+use crate::runner::{self,Run,Source,Status};
+
+// Modification time of each watched file, None once deleted
+pub type Snapshot = Vec<(PathBuf, Option<SystemTime>)>;
+
+// Input and examples of the day, plus the `--input` file, examples added later are picked up by the next snapshot
+pub fn files (solution: &dyn Solution, source: &Source) -> Vec<PathBuf> {
+    let mut files = vec![PathBuf::from(format!("{}.txt", runner::inputs(solution)))];
+    if let Ok(entries) = fs::read_dir(runner::inputs(solution)) {
+        let mut examples: Vec<PathBuf> = entries.filter_map(|entry| entry.ok().map(|entry| entry.path())).collect();
+        examples.sort();
+        files.extend(examples);
+    }
+    if let Source::File(path) = source {
+        files.push(PathBuf::from(path));
+    }
+    files
+}
+
+pub fn snapshot (files: &[PathBuf]) -> Snapshot {
+    files
+        .iter()
+        .map(|path| (path.clone(), fs::metadata(path).and_then(|metadata| metadata.modified()).ok()))
+        .collect()
+}
+
+// Helper
+fn outcome (run: &Run) -> String {
+    match &run.status {
+        Status::Ok | Status::Wrong(_) | Status::Unverified => run.answer.trim_end().to_string(),
+        Status::Failed(err) => format!("failed: {}", err),
+        Status::Timeout(timeout) => format!("gave up after {:.2?}", timeout),
+        status => status.to_string()
+    }
+}
+
+// What changed since the previous runs of the same parts, multi-line answers on their own lines
+pub fn changes (previous: &[Run], current: &[Run]) -> Vec<String> {
+    current
+        .iter()
+        .zip(previous)
+        .map(|(current, previous)| {
+            let (before, after) = (outcome(previous), outcome(current));
+            if before == after {
+                format!("Part{}: unchanged", current.part)
+            } else if before.contains('\n') || after.contains('\n') {
+                format!("Part{}: changed from\n{}\nto\n{}", current.part, before, after)
+            } else {
+                format!("Part{}: {} -> {}", current.part, before, after)
+            }
+        })
+        .collect()
+}
+
+// Tests
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use crate::days;
+    use crate::params::Params;
+    use crate::runner::{self,Part,Source};
+
+    #[test]
+    fn watch_files () {
+        let day3 = days::find("day3").unwrap();
+        let files = super::files(day3, &Source::File(String::from("/tmp/day3.txt")));
+        assert_eq!(files[0], PathBuf::from("./inputs/2019/day3.txt"));
+        assert!(files.contains(&PathBuf::from("./inputs/2019/day3/example1.txt")));
+        assert_eq!(files.last(), Some(&PathBuf::from("/tmp/day3.txt")));
+
+        let snapshot = super::snapshot(&files);
+        assert!(snapshot[0].1.is_some());
+        assert_eq!(snapshot.last().map(|(_, modified)| *modified), Some(None));
+    }
+
+    #[test]
+    fn watch_changes () {
+        let day1 = days::find("day1").unwrap();
+        let run = |part, input| runner::run_part(day1, part, input, &Params::default());
+        let previous = vec![run(Part::One, "12"), run(Part::Two, "12")];
+        let current = vec![run(Part::One, "14"), run(Part::Two, "x")];
+        assert_eq!(super::changes(&previous, &previous), vec!["Part1: unchanged", "Part2: unchanged"]);
+        let changes = super::changes(&previous, &current);
+        assert_eq!(changes[0], "Part1: unchanged");
+        assert!(changes[1].starts_with("Part2: 2 -> failed: day1 part 2: parse error"));
+    }
+}
@@ -0,0 +1,59 @@
+// Tests of the public API, as used by other crates
+use advent_of_code_2019::{days,intcode,memory,runner};
+use advent_of_code_2019::{Machine,Params,Part,Solution,SolveError,Source,Status};
+use advent_of_code_2019::intcode::hooks::Action;
+
+#[test]
+fn library_machine () {
+    // Echo the input, then halt
+    let opcodes = intcode::parse("3,0,4,0,99").unwrap();
+    let mut machine = Machine::new(&opcodes).add_input(42);
+    assert!(matches!(machine.run_until_interrupted(), intcode::Status::Output(42)));
+    assert!(matches!(machine.run_until_interrupted(), intcode::Status::Halt));
+
+    // Hooks see the memory accesses of the machine
+    let mut machine = Machine::new(&opcodes).add_input(7);
+    machine.hooks.on_write(0..=0, |_, value| Action::Substitute(value * 2));
+    assert!(matches!(machine.run_until_interrupted(), intcode::Status::Output(14)));
+    assert_eq!(intcode::diff::diff_machines(&Machine::new(&opcodes), &machine).len(), 1);
+
+    assert!(intcode::parse("1,x").is_err());
+}
+
+#[test]
+fn library_days () {
+    let day1: &dyn Solution = days::find("day1").unwrap();
+    assert_eq!(day1.title(), "The Tyranny of the Rocket Equation");
+    assert_eq!(day1.part2("1969", &Params::default()).unwrap(), "966");
+    assert_eq!(days::y2019::day1::part1("12").unwrap(), "2");
+    assert_eq!(days::find("2019/day1").map(|solution| solution.name()), Some(String::from("day1")));
+
+    let err: SolveError = day1.part1("12\nfuel", &Params::default()).unwrap_err();
+    assert_eq!(err.at(day1, Part::One).day, Some(1));
+    assert_eq!(days::all().len(), 25);
+
+    // Parameters declared by a day, with their defaults
+    let day8 = days::find("day8").unwrap();
+    let params = Params::resolve(day8.params(), &[(String::from("width"), 3), (String::from("height"), 2)]);
+    assert_eq!(day8.part1("123456789012", &params).unwrap(), "1");
+    assert_eq!(Params::defaults(day8.params()).get::<usize>("width"), Ok(25));
+}
+
+#[test]
+fn library_runner () {
+    let solutions = runner::select("day1..day2").unwrap();
+    let source = Source::Inline(String::from("1969"));
+    let runs = runner::run_all(&solutions[..1], &source, 2, runner::Limits::default(), &[], None, |_| {});
+    assert_eq!(runs.len(), 2);
+    assert_eq!((&runs[0].status, runs[0].answer.as_str()), (&Status::Ok, "654"));
+    assert_eq!((&runs[1].status, runs[1].answer.as_str()), (&Status::Ok, "966"));
+    assert!(runner::json(&runs[1]).contains("\"answer\":\"966\""));
+
+    // Without the counting allocator, memory can't be measured
+    assert!(!memory::available());
+    assert_eq!(memory::peak_since(memory::reset_peak()), None);
+    let limits = runner::Limits { timeout: None, memory: true };
+    let runs = runner::run_all(&solutions[..1], &source, 1, limits, &[], None, |_| {});
+    assert_eq!(runs[0].memory, None);
+    assert!(runner::row(&runs[0], true).contains(" -  "));
+}